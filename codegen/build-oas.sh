@@ -13,11 +13,14 @@ if [ -z "$version" ]; then
 	exit 1
 fi
 
+# supportMiddleware generates a client built on reqwest_middleware::ClientWithMiddleware, with an
+# Error::ReqwestMiddleware variant. The SDK relies on it to propagate the OpenTelemetry context of control plane
+# and inference requests, so src/openapi/apis/{configuration,mod}.rs must not be edited by hand.
 docker run --rm -v $(pwd):/workspace openapitools/openapi-generator-cli:v7.6.0 generate \
 	--input-spec /workspace/codegen/apis/_build/$version/control_$version.oas.yaml \
 	--generator-name rust \
 	--output /workspace/$tempdir \
-	--additional-properties "packageVersion=0.0.1"
+	--additional-properties "packageVersion=0.0.1,supportMiddleware=true"
 
 # copy source files from the crate to the module (outdir)
 echo "Copying source files from $tempdir to $outdir"
@@ -1,6 +1,76 @@
 use std::error::Error;
 use std::path::Path;
 
+// Data plane messages that implement `Serialize` and `Deserialize`
+const SERDE_MESSAGES: &[&str] = &[
+    ".SparseValues",
+    ".Vector",
+    ".ScoredVector",
+    ".UpsertRequest",
+    ".UpsertResponse",
+    ".DeleteRequest",
+    ".DeleteResponse",
+    ".FetchRequest",
+    ".FetchResponse",
+    ".ListRequest",
+    ".Pagination",
+    ".ListItem",
+    ".ListResponse",
+    ".QueryVector",
+    ".QueryRequest",
+    ".SingleQueryResults",
+    ".QueryResponse",
+    ".Usage",
+    ".UpdateRequest",
+    ".UpdateResponse",
+    ".DescribeIndexStatsRequest",
+    ".NamespaceSummary",
+    ".DescribeIndexStatsResponse",
+];
+
+// Optional fields that are omitted from JSON when they are not set
+const SERDE_OPTIONAL_FIELDS: &[&str] = &[
+    ".Vector.sparse_values",
+    ".ScoredVector.sparse_values",
+    ".FetchResponse.usage",
+    ".ListRequest.prefix",
+    ".ListRequest.limit",
+    ".ListRequest.pagination_token",
+    ".ListResponse.pagination",
+    ".ListResponse.usage",
+    ".QueryVector.sparse_values",
+    ".QueryRequest.sparse_vector",
+    ".QueryResponse.usage",
+    ".Usage.read_units",
+    ".UpdateRequest.sparse_values",
+];
+
+// Fields that the REST API expects to be omitted when they are empty, such as the vector of a query by ID
+const SERDE_EMPTY_FIELDS: &[(&str, &str)] = &[
+    (".DeleteRequest.ids", "Vec::is_empty"),
+    (".QueryRequest.vector", "Vec::is_empty"),
+    (".QueryRequest.id", "String::is_empty"),
+    (".UpdateRequest.values", "Vec::is_empty"),
+];
+
+// Deprecated fields that are omitted from JSON when they are empty
+const SERDE_DEPRECATED_FIELDS: &[&str] = &[".QueryRequest.queries", ".QueryResponse.results"];
+
+// Metadata fields, which are represented as plain JSON objects
+const SERDE_METADATA_FIELDS: &[&str] = &[
+    ".Vector.metadata",
+    ".ScoredVector.metadata",
+    ".UpdateRequest.set_metadata",
+];
+
+// Filter fields, which are represented as plain JSON objects whose lists may contain objects
+const SERDE_FILTER_FIELDS: &[&str] = &[
+    ".DeleteRequest.filter",
+    ".QueryVector.filter",
+    ".QueryRequest.filter",
+    ".DescribeIndexStatsRequest.filter",
+];
+
 fn main() -> Result<(), Box<dyn Error>> {
     let args: Vec<String> = std::env::args().collect();
 
@@ -28,10 +98,56 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let include_dirs = [proto_dir, include_dir];
 
-    tonic_build::configure()
+    let mut builder = tonic_build::configure()
         .out_dir(out_dir)
-        .protoc_arg("--experimental_allow_proto3_optional")
-        .compile_protos(&[proto_path], &include_dirs[..])?;
+        .protoc_arg("--experimental_allow_proto3_optional");
+
+    // serde support for the data plane messages behind the `serde` feature of the SDK, in the JSON shape of the REST API
+    for message in SERDE_MESSAGES {
+        builder = builder
+            .type_attribute(
+                message,
+                r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#,
+            )
+            .type_attribute(
+                message,
+                r#"#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]"#,
+            );
+    }
+    for field in SERDE_OPTIONAL_FIELDS {
+        builder = builder.field_attribute(
+            field,
+            r#"#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]"#,
+        );
+    }
+    for (field, is_empty) in SERDE_EMPTY_FIELDS {
+        builder = builder.field_attribute(
+            field,
+            format!(
+                r#"#[cfg_attr(feature = "serde", serde(skip_serializing_if = "{is_empty}"))]"#
+            ),
+        );
+    }
+    for field in SERDE_DEPRECATED_FIELDS {
+        builder = builder.field_attribute(
+            field,
+            r#"#[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]"#,
+        );
+    }
+    for field in SERDE_METADATA_FIELDS {
+        builder = builder.field_attribute(
+            field,
+            r#"#[cfg_attr(feature = "serde", serde(with = "crate::models::metadata_json", skip_serializing_if = "Option::is_none"))]"#,
+        );
+    }
+    for field in SERDE_FILTER_FIELDS {
+        builder = builder.field_attribute(
+            field,
+            r#"#[cfg_attr(feature = "serde", serde(with = "crate::models::filter_json", skip_serializing_if = "Option::is_none"))]"#,
+        );
+    }
+
+    builder.compile_protos(&[proto_path], &include_dirs[..])?;
 
     Ok(())
 }
@@ -0,0 +1,662 @@
+#[cfg(any(feature = "control", feature = "data"))]
+use crate::models::IndexModel;
+#[cfg(feature = "control")]
+use crate::models::{
+    Cloud, CollectionList, CollectionModel, DeletionProtection, IndexList, Metric, WaitPolicy,
+};
+#[cfg(feature = "data")]
+use crate::models::{
+    DescribeIndexStatsResponse, FetchResponse, ListResponse, Metadata, Namespace, QueryResponse,
+    SparseValues, UpdateResponse, UpsertBatchResponse, UpsertResponse, Vector,
+};
+#[cfg(feature = "inference")]
+use crate::models::{EmbedRequestParameters, EmbeddingsList};
+use crate::pinecone::{self, PineconeClientConfig};
+use crate::utils::errors::PineconeError;
+#[cfg(feature = "data")]
+use futures::StreamExt;
+use std::sync::Arc;
+#[cfg(feature = "data")]
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// A synchronous client for Pinecone, which mirrors `pinecone::PineconeClient`.
+///
+/// The client owns a Tokio runtime, on which every operation is run to completion before the method returns. It must
+/// not be created, used or dropped from within an async context, where blocking would stall the executor; use
+/// `pinecone::PineconeClient` there instead.
+///
+/// `PineconeClient` is cheap to clone: clones share the same runtime and connections.
+#[derive(Debug, Clone)]
+pub struct PineconeClient {
+    inner: pinecone::PineconeClient,
+    runtime: Arc<Runtime>,
+}
+
+/// Constructs a synchronous `PineconeClient` from environment variables. See `pinecone::default_client()`.
+///
+/// ### Return
+/// * `Result<PineconeClient, PineconeError>`
+///
+/// ### Example
+/// ```no_run
+/// use pinecone_sdk::blocking::PineconeClient;
+///
+/// let pinecone: PineconeClient = pinecone_sdk::blocking::default_client().expect("Failed to create Pinecone instance");
+/// ```
+pub fn default_client() -> Result<PineconeClient, PineconeError> {
+    PineconeClient::new(PineconeClientConfig::default())
+}
+
+impl TryFrom<PineconeClientConfig> for PineconeClient {
+    type Error = PineconeError;
+
+    fn try_from(config: PineconeClientConfig) -> Result<Self, Self::Error> {
+        PineconeClient::new(config)
+    }
+}
+
+impl PineconeClient {
+    /// Constructs a synchronous `PineconeClient` from the configuration. See `PineconeClientConfig::client()`.
+    ///
+    /// ### Arguments
+    /// * `config: PineconeClientConfig` - The configuration of the client.
+    ///
+    /// ### Return
+    /// * `Result<PineconeClient, PineconeError>`
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use pinecone_sdk::blocking::PineconeClient;
+    /// use pinecone_sdk::pinecone::PineconeClientConfig;
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # fn main() -> Result<(), PineconeError> {
+    /// let config = PineconeClientConfig {
+    ///     api_key: Some("INSERT_API_KEY".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let pinecone = PineconeClient::new(config)?;
+    ///
+    /// let index_description = pinecone.describe_index("index-name")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(config: PineconeClientConfig) -> Result<Self, PineconeError> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("pinecone-blocking")
+            .enable_all()
+            .build()
+            .map_err(|e| PineconeError::IoError {
+                message: format!("Failed to create runtime: {e}"),
+            })?;
+
+        Ok(PineconeClient {
+            inner: config.client()?,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Creates a serverless index. See `pinecone::PineconeClient::create_serverless_index`.
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "control")]
+    pub fn create_serverless_index(
+        &self,
+        name: &str,
+        dimension: i32,
+        metric: Metric,
+        cloud: Cloud,
+        region: &str,
+        deletion_protection: DeletionProtection,
+        timeout: WaitPolicy,
+    ) -> Result<IndexModel, PineconeError> {
+        self.runtime.block_on(self.inner.create_serverless_index(
+            name,
+            dimension,
+            metric,
+            cloud,
+            region,
+            deletion_protection,
+            timeout,
+        ))
+    }
+
+    /// Creates a pod index. See `pinecone::PineconeClient::create_pod_index`.
+    #[allow(clippy::too_many_arguments)]
+    #[cfg(feature = "control")]
+    pub fn create_pod_index(
+        &self,
+        name: &str,
+        dimension: i32,
+        metric: Metric,
+        environment: &str,
+        pod_type: &str,
+        pods: i32,
+        replicas: i32,
+        shards: i32,
+        deletion_protection: DeletionProtection,
+        metadata_indexed: Option<&[&str]>,
+        source_collection: Option<&str>,
+        timeout: WaitPolicy,
+    ) -> Result<IndexModel, PineconeError> {
+        self.runtime.block_on(self.inner.create_pod_index(
+            name,
+            dimension,
+            metric,
+            environment,
+            pod_type,
+            pods,
+            replicas,
+            shards,
+            deletion_protection,
+            metadata_indexed,
+            source_collection,
+            timeout,
+        ))
+    }
+
+    /// Describes an index. See `pinecone::PineconeClient::describe_index`.
+    #[cfg(feature = "control")]
+    pub fn describe_index(&self, name: &str) -> Result<IndexModel, PineconeError> {
+        self.runtime.block_on(self.inner.describe_index(name))
+    }
+
+    /// Lists all indexes. See `pinecone::PineconeClient::list_indexes`.
+    #[cfg(feature = "control")]
+    pub fn list_indexes(&self) -> Result<IndexList, PineconeError> {
+        self.runtime.block_on(self.inner.list_indexes())
+    }
+
+    /// Configures an index. See `pinecone::PineconeClient::configure_index`.
+    #[cfg(feature = "control")]
+    pub fn configure_index(
+        &self,
+        name: &str,
+        deletion_protection: Option<DeletionProtection>,
+        replicas: Option<i32>,
+        pod_type: Option<&str>,
+    ) -> Result<IndexModel, PineconeError> {
+        self.runtime.block_on(self.inner.configure_index(
+            name,
+            deletion_protection,
+            replicas,
+            pod_type,
+        ))
+    }
+
+    /// Deletes an index. See `pinecone::PineconeClient::delete_index`.
+    #[cfg(feature = "control")]
+    pub fn delete_index(&self, name: &str) -> Result<(), PineconeError> {
+        self.runtime.block_on(self.inner.delete_index(name))
+    }
+
+    /// Creates a collection from an index. See `pinecone::PineconeClient::create_collection`.
+    #[cfg(feature = "control")]
+    pub fn create_collection(
+        &self,
+        name: &str,
+        source: &str,
+    ) -> Result<CollectionModel, PineconeError> {
+        self.runtime
+            .block_on(self.inner.create_collection(name, source))
+    }
+
+    /// Describes a collection. See `pinecone::PineconeClient::describe_collection`.
+    #[cfg(feature = "control")]
+    pub fn describe_collection(&self, name: &str) -> Result<CollectionModel, PineconeError> {
+        self.runtime.block_on(self.inner.describe_collection(name))
+    }
+
+    /// Lists all collections. See `pinecone::PineconeClient::list_collections`.
+    #[cfg(feature = "control")]
+    pub fn list_collections(&self) -> Result<CollectionList, PineconeError> {
+        self.runtime.block_on(self.inner.list_collections())
+    }
+
+    /// Deletes a collection. See `pinecone::PineconeClient::delete_collection`.
+    #[cfg(feature = "control")]
+    pub fn delete_collection(&self, name: &str) -> Result<(), PineconeError> {
+        self.runtime.block_on(self.inner.delete_collection(name))
+    }
+
+    /// Generates embeddings for input data. See `pinecone::PineconeClient::embed`.
+    #[cfg(feature = "inference")]
+    pub fn embed(
+        &self,
+        model: &str,
+        parameters: Option<EmbedRequestParameters>,
+        inputs: &[&str],
+    ) -> Result<EmbeddingsList, PineconeError> {
+        self.runtime
+            .block_on(self.inner.embed(model, parameters, inputs))
+    }
+
+    /// Targets an index for data operations by its host. See `pinecone::PineconeClient::index`.
+    ///
+    /// ### Arguments
+    /// * `host: &str` - The host of the index to target.
+    ///
+    /// ### Return
+    /// * `Result<Index, PineconeError>`
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use pinecone_sdk::models::Namespace;
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # fn main() -> Result<(), PineconeError> {
+    /// let pinecone = pinecone_sdk::blocking::default_client()?;
+    ///
+    /// let index = pinecone.index("index-host")?;
+    /// let response = index.fetch(&["1", "2"], &Namespace::default())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "data")]
+    pub fn index(&self, host: &str) -> Result<Index, PineconeError> {
+        // the connection must be established on the runtime, which drives it in the background
+        let inner = self.runtime.block_on(self.inner.index(host))?;
+
+        Ok(Index {
+            inner,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// Targets an index for data operations by its name. See `pinecone::PineconeClient::index_by_name`.
+    #[cfg(all(feature = "control", feature = "data"))]
+    pub fn index_by_name(&self, name: &str) -> Result<Index, PineconeError> {
+        let inner = self.runtime.block_on(self.inner.index_by_name(name))?;
+
+        Ok(Index {
+            inner,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// Targets an index by its name, with client-side validation of vectors and queries. See
+    /// `pinecone::PineconeClient::index_with_validation`.
+    #[cfg(all(feature = "control", feature = "data"))]
+    pub fn index_with_validation(&self, name: &str) -> Result<Index, PineconeError> {
+        let inner = self
+            .runtime
+            .block_on(self.inner.index_with_validation(name))?;
+
+        Ok(Index {
+            inner,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
+
+/// A synchronous client for a Pinecone index, which mirrors `pinecone::data::Index`.
+///
+/// `Index` is cheap to clone: clones share the same runtime and connection.
+#[cfg(feature = "data")]
+#[derive(Debug, Clone)]
+pub struct Index {
+    inner: pinecone::data::Index,
+    runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "data")]
+impl Index {
+    /// Enables client-side validation of vectors and queries. See `pinecone::data::Index::with_validation`.
+    pub fn with_validation(self, index: &IndexModel) -> Self {
+        Index {
+            inner: self.inner.with_validation(index),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Returns a copy of the index whose operations must complete within `deadline`. See
+    /// `pinecone::data::Index::with_deadline`.
+    pub fn with_deadline(&self, deadline: Duration) -> Self {
+        Index {
+            inner: self.inner.with_deadline(deadline),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Writes vectors into a namespace. See `pinecone::data::Index::upsert`.
+    pub fn upsert(
+        &self,
+        vectors: &[Vector],
+        namespace: &Namespace,
+    ) -> Result<UpsertResponse, PineconeError> {
+        self.runtime.block_on(self.inner.upsert(vectors, namespace))
+    }
+
+    /// Writes vectors into a namespace in concurrent batches. See `pinecone::data::Index::upsert_batched`.
+    pub fn upsert_batched(
+        &self,
+        vectors: &[Vector],
+        namespace: &Namespace,
+        batch_size: Option<usize>,
+        max_concurrency: Option<usize>,
+    ) -> Result<UpsertBatchResponse, PineconeError> {
+        self.runtime.block_on(self.inner.upsert_batched(
+            vectors,
+            namespace,
+            batch_size,
+            max_concurrency,
+        ))
+    }
+
+    /// Lists a page of vector IDs in a namespace. See `pinecone::data::Index::list`.
+    pub fn list(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+        limit: Option<u32>,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse, PineconeError> {
+        self.runtime
+            .block_on(self.inner.list(namespace, prefix, limit, pagination_token))
+    }
+
+    /// Returns an iterator over every page of vector IDs in a namespace, which requests each page as it is
+    /// reached. See `pinecone::data::Index::list_pages`.
+    pub fn list_pages(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+    ) -> impl Iterator<Item = Result<ListResponse, PineconeError>> + '_ {
+        let mut pages = self.inner.list_pages(namespace, prefix);
+        std::iter::from_fn(move || self.runtime.block_on(pages.next()))
+    }
+
+    /// Returns an iterator over the IDs of every vector in a namespace, up to `limit`. See
+    /// `pinecone::data::Index::list_all`.
+    pub fn list_all(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+        limit: Option<usize>,
+    ) -> impl Iterator<Item = Result<String, PineconeError>> + '_ {
+        let mut ids = self.inner.list_all(namespace, prefix, limit);
+        std::iter::from_fn(move || self.runtime.block_on(ids.next()))
+    }
+
+    /// Describes the contents of the index. See `pinecone::data::Index::describe_index_stats`.
+    pub fn describe_index_stats(
+        &self,
+        filter: Option<Metadata>,
+    ) -> Result<DescribeIndexStatsResponse, PineconeError> {
+        self.runtime
+            .block_on(self.inner.describe_index_stats(filter))
+    }
+
+    /// Updates a vector. See `pinecone::data::Index::update`.
+    pub fn update(
+        &self,
+        id: &str,
+        values: Vec<f32>,
+        sparse_values: Option<SparseValues>,
+        metadata: Option<Metadata>,
+        namespace: &Namespace,
+    ) -> Result<UpdateResponse, PineconeError> {
+        self.runtime.block_on(
+            self.inner
+                .update(id, values, sparse_values, metadata, namespace),
+        )
+    }
+
+    /// Queries the vectors closest to a stored vector. See `pinecone::data::Index::query_by_id`.
+    pub fn query_by_id(
+        &self,
+        id: &str,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError> {
+        self.runtime.block_on(self.inner.query_by_id(
+            id,
+            top_k,
+            namespace,
+            filter,
+            include_values,
+            include_metadata,
+        ))
+    }
+
+    /// Queries the vectors closest to a dense and/or sparse vector. See `pinecone::data::Index::query_by_value`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn query_by_value(
+        &self,
+        vector: Vec<f32>,
+        sparse_vector: Option<SparseValues>,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError> {
+        self.runtime.block_on(self.inner.query_by_value(
+            vector,
+            sparse_vector,
+            top_k,
+            namespace,
+            filter,
+            include_values,
+            include_metadata,
+        ))
+    }
+
+    /// Returns a builder to configure and send a query step by step. See `pinecone::data::Index::query_builder`.
+    pub fn query_builder(&self) -> QueryBuilder<'_> {
+        QueryBuilder {
+            inner: self.inner.query_builder(),
+            runtime: &self.runtime,
+        }
+    }
+
+    /// Deletes vectors by ID. See `pinecone::data::Index::delete_by_id`.
+    pub fn delete_by_id(&self, ids: &[&str], namespace: &Namespace) -> Result<(), PineconeError> {
+        self.runtime
+            .block_on(self.inner.delete_by_id(ids, namespace))
+    }
+
+    /// Deletes every vector in a namespace. See `pinecone::data::Index::delete_all`.
+    pub fn delete_all(&self, namespace: &Namespace) -> Result<(), PineconeError> {
+        self.runtime.block_on(self.inner.delete_all(namespace))
+    }
+
+    /// Deletes the vectors that match a metadata filter. See `pinecone::data::Index::delete_by_filter`.
+    pub fn delete_by_filter(
+        &self,
+        filter: Metadata,
+        namespace: &Namespace,
+    ) -> Result<(), PineconeError> {
+        self.runtime
+            .block_on(self.inner.delete_by_filter(filter, namespace))
+    }
+
+    /// Fetches vectors by ID. See `pinecone::data::Index::fetch`.
+    pub fn fetch(
+        &self,
+        ids: &[&str],
+        namespace: &Namespace,
+    ) -> Result<FetchResponse, PineconeError> {
+        self.runtime.block_on(self.inner.fetch(ids, namespace))
+    }
+}
+
+/// A synchronous builder for a query, which mirrors `pinecone::data::QueryBuilder`.
+#[cfg(feature = "data")]
+#[derive(Debug)]
+pub struct QueryBuilder<'a> {
+    inner: pinecone::data::QueryBuilder<'a>,
+    runtime: &'a Runtime,
+}
+
+#[cfg(feature = "data")]
+impl<'a> QueryBuilder<'a> {
+    /// Sets the number of results to return. Required.
+    pub fn top_k(self, top_k: u32) -> Self {
+        self.map(|inner| inner.top_k(top_k))
+    }
+
+    /// Sets the namespace to query. Default is "".
+    pub fn namespace(self, namespace: impl Into<Namespace>) -> Self {
+        self.map(|inner| inner.namespace(namespace))
+    }
+
+    /// Sets the filter to apply to limit the search by vector metadata.
+    pub fn filter(self, filter: Metadata) -> Self {
+        self.map(|inner| inner.filter(filter))
+    }
+
+    /// Sets whether vector values are included in the response. Default is false.
+    pub fn include_values(self, include_values: bool) -> Self {
+        self.map(|inner| inner.include_values(include_values))
+    }
+
+    /// Sets whether vector metadata is included in the response. Default is false.
+    pub fn include_metadata(self, include_metadata: bool) -> Self {
+        self.map(|inner| inner.include_metadata(include_metadata))
+    }
+
+    /// Sets the dense vector to query with.
+    pub fn vector(self, vector: Vec<f32>) -> Self {
+        self.map(|inner| inner.vector(vector))
+    }
+
+    /// Sets the sparse vector to query with.
+    pub fn sparse_vector(self, sparse_vector: SparseValues) -> Self {
+        self.map(|inner| inner.sparse_vector(sparse_vector))
+    }
+
+    /// Sets the ID of the stored vector to query with.
+    pub fn id(self, id: &str) -> Self {
+        self.map(|inner| inner.id(id))
+    }
+
+    /// Sets the deadline of the query, overriding the deadline of the index.
+    pub fn deadline(self, deadline: Duration) -> Self {
+        self.map(|inner| inner.deadline(deadline))
+    }
+
+    /// Sends the query. See `pinecone::data::QueryBuilder::execute`.
+    pub fn execute(self) -> Result<QueryResponse, PineconeError> {
+        self.runtime.block_on(self.inner.execute())
+    }
+
+    // Helper function to apply a setter of the asynchronous builder
+    fn map(
+        self,
+        f: impl FnOnce(pinecone::data::QueryBuilder<'a>) -> pinecone::data::QueryBuilder<'a>,
+    ) -> Self {
+        QueryBuilder {
+            inner: f(self.inner),
+            runtime: self.runtime,
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "control", feature = "data")))]
+mod tests {
+    use super::*;
+    #[cfg(feature = "control")]
+    use httpmock::prelude::*;
+    #[cfg(feature = "data")]
+    use std::net::TcpListener;
+
+    // Starts a server that accepts connections but never responds
+    #[cfg(feature = "data")]
+    fn unresponsive_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            let mut sockets = Vec::new();
+            for socket in listener.incoming() {
+                sockets.push(socket);
+            }
+        });
+
+        format!("http://{address}")
+    }
+
+    #[cfg(feature = "control")]
+    #[test]
+    fn test_describe_index() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/indexes/index-name");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{
+                        "dimension": 1536,
+                        "host": "mock-host",
+                        "metric": "cosine",
+                        "name": "index-name",
+                        "spec": {
+                            "serverless": {
+                                "cloud": "aws",
+                                "region": "us-east-1"
+                            }
+                        },
+                        "status": {
+                            "ready": true,
+                            "state": "Ready"
+                        }
+                    }"#,
+                );
+        });
+
+        let pinecone = PineconeClient::new(PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            control_plane_host: Some(server.base_url()),
+            ..Default::default()
+        })
+        .expect("Failed to create Pinecone instance");
+
+        let index = pinecone
+            .describe_index("index-name")
+            .expect("Failed to describe index");
+
+        assert_eq!(index.name, "index-name");
+        assert_eq!(index.dimension, 1536);
+        mock.assert();
+    }
+
+    #[cfg(feature = "data")]
+    #[test]
+    fn test_index_deadline() {
+        let pinecone = PineconeClient::new(PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            ..Default::default()
+        })
+        .expect("Failed to create Pinecone instance");
+
+        let index = pinecone
+            .index(&unresponsive_server())
+            .expect("Failed to target index")
+            .with_deadline(Duration::from_millis(100));
+
+        let error = index
+            .fetch(&["1"], &Namespace::default())
+            .expect_err("Expected fetch to exceed its deadline");
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+
+        let error = index
+            .query_builder()
+            .id("1")
+            .top_k(10)
+            .execute()
+            .expect_err("Expected query to exceed its deadline");
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+    }
+}
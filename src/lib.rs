@@ -93,7 +93,7 @@
 //! # async fn upsert_and_query_vectors() -> Result<(), PineconeError> {
 //!     let client = pinecone::default_client().expect("Failed to initialize PineconeClient");
 //!     let index_description = client.describe_index("my-index").await?;
-//!     let mut index = client.index(&index_description.host).await?;
+//!     let index = client.index(&index_description.host).await?;
 //!
 //!     // upsert vectors
 //!     let vectors = [Vector {
@@ -166,6 +166,11 @@
 // `derivable_impls` on its `Default` impls. Allow at crate level rather than hand-editing
 // generated files, which regeneration would overwrite.
 #![allow(clippy::derivable_impls)]
+// Without any of the `control`, `data` or `inference` features, the client has no operations to send.
+#![cfg_attr(
+    not(any(feature = "control", feature = "data", feature = "inference")),
+    allow(dead_code)
+)]
 
 /// Defines the main entrypoint of the Pinecone SDK.
 pub mod pinecone;
@@ -179,12 +184,21 @@ pub mod models;
 /// Version information.
 pub mod version;
 
+/// Synchronous client for non-async code.
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+/// In-process fake Pinecone services for offline tests.
+#[cfg(feature = "testing")]
+pub mod testing;
+
 /// OpenAPI client for Pinecone.
 #[allow(missing_docs)]
 #[allow(dead_code)]
 mod openapi;
 
 /// Protobuf client for Pinecone.
+#[cfg(feature = "data")]
 #[allow(missing_docs)]
 #[allow(dead_code)]
 mod protos;
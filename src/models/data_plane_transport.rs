@@ -0,0 +1,9 @@
+/// The protocol that data plane operations are sent with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DataPlaneTransport {
+    /// gRPC over HTTP/2
+    #[default]
+    Grpc,
+    /// JSON over HTTP, for networks whose proxies or load balancers do not support gRPC
+    Rest,
+}
@@ -0,0 +1,441 @@
+use super::{Kind, Metadata, Value};
+use crate::utils::errors::PineconeError;
+use prost_types::ListValue;
+use std::collections::BTreeMap;
+
+/// FilterValue : A value that a metadata field can be compared to in a `Filter`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterValue {
+    /// A string value
+    String(String),
+    /// A number value
+    Number(f64),
+    /// A boolean value
+    Boolean(bool),
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::String(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::String(value)
+    }
+}
+
+impl From<f64> for FilterValue {
+    fn from(value: f64) -> Self {
+        FilterValue::Number(value)
+    }
+}
+
+impl From<f32> for FilterValue {
+    fn from(value: f32) -> Self {
+        FilterValue::Number(value.into())
+    }
+}
+
+impl From<i32> for FilterValue {
+    fn from(value: i32) -> Self {
+        FilterValue::Number(value.into())
+    }
+}
+
+impl From<u32> for FilterValue {
+    fn from(value: u32) -> Self {
+        FilterValue::Number(value.into())
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        FilterValue::Boolean(value)
+    }
+}
+
+impl FilterValue {
+    fn to_value(&self) -> Value {
+        let kind = match self {
+            FilterValue::String(s) => Kind::StringValue(s.clone()),
+            FilterValue::Number(n) => Kind::NumberValue(*n),
+            FilterValue::Boolean(b) => Kind::BoolValue(*b),
+        };
+        Value { kind: Some(kind) }
+    }
+}
+
+/// Filter : A metadata filter, used to limit queries, deletes, and index statistics to the vectors whose metadata matches it.
+///
+/// A filter is compiled to `Metadata` with `build()`, which rejects filters that Pinecone would not accept.
+///
+/// ### Example
+/// ```
+/// use pinecone_sdk::models::{Filter, Metadata};
+/// # use pinecone_sdk::utils::errors::PineconeError;
+///
+/// # fn main() -> Result<(), PineconeError> {
+/// // {"$and": [{"genre": {"$in": ["comedy", "drama"]}}, {"year": {"$gte": 2020}}]}
+/// let filter: Metadata = Filter::and(vec![
+///     Filter::is_in("genre", vec!["comedy", "drama"]),
+///     Filter::gte("year", 2020),
+/// ])
+/// .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    /// Matches vectors whose field is equal to the value: `$eq`
+    Eq(String, FilterValue),
+    /// Matches vectors whose field is not equal to the value: `$ne`
+    Ne(String, FilterValue),
+    /// Matches vectors whose field is greater than the value: `$gt`
+    Gt(String, f64),
+    /// Matches vectors whose field is greater than or equal to the value: `$gte`
+    Gte(String, f64),
+    /// Matches vectors whose field is less than the value: `$lt`
+    Lt(String, f64),
+    /// Matches vectors whose field is less than or equal to the value: `$lte`
+    Lte(String, f64),
+    /// Matches vectors whose field is equal to one of the values: `$in`
+    In(String, Vec<FilterValue>),
+    /// Matches vectors whose field is equal to none of the values: `$nin`
+    Nin(String, Vec<FilterValue>),
+    /// Matches vectors that have the field if true, or do not have it if false: `$exists`
+    Exists(String, bool),
+    /// Matches vectors that match all of the filters: `$and`
+    And(Vec<Filter>),
+    /// Matches vectors that match at least one of the filters: `$or`
+    Or(Vec<Filter>),
+}
+
+impl Filter {
+    /// Creates an `$eq` filter.
+    pub fn eq(field: &str, value: impl Into<FilterValue>) -> Self {
+        Filter::Eq(field.to_string(), value.into())
+    }
+
+    /// Creates a `$ne` filter.
+    pub fn ne(field: &str, value: impl Into<FilterValue>) -> Self {
+        Filter::Ne(field.to_string(), value.into())
+    }
+
+    /// Creates a `$gt` filter.
+    pub fn gt(field: &str, value: impl Into<f64>) -> Self {
+        Filter::Gt(field.to_string(), value.into())
+    }
+
+    /// Creates a `$gte` filter.
+    pub fn gte(field: &str, value: impl Into<f64>) -> Self {
+        Filter::Gte(field.to_string(), value.into())
+    }
+
+    /// Creates a `$lt` filter.
+    pub fn lt(field: &str, value: impl Into<f64>) -> Self {
+        Filter::Lt(field.to_string(), value.into())
+    }
+
+    /// Creates a `$lte` filter.
+    pub fn lte(field: &str, value: impl Into<f64>) -> Self {
+        Filter::Lte(field.to_string(), value.into())
+    }
+
+    /// Creates an `$in` filter.
+    pub fn is_in<T: Into<FilterValue>>(field: &str, values: impl IntoIterator<Item = T>) -> Self {
+        Filter::In(
+            field.to_string(),
+            values.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Creates a `$nin` filter.
+    pub fn not_in<T: Into<FilterValue>>(field: &str, values: impl IntoIterator<Item = T>) -> Self {
+        Filter::Nin(
+            field.to_string(),
+            values.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Creates an `$exists` filter.
+    pub fn exists(field: &str, exists: bool) -> Self {
+        Filter::Exists(field.to_string(), exists)
+    }
+
+    /// Creates an `$and` filter.
+    pub fn and(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Filter::And(filters.into_iter().collect())
+    }
+
+    /// Creates an `$or` filter.
+    pub fn or(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Filter::Or(filters.into_iter().collect())
+    }
+
+    /// Compiles the filter to `Metadata`, that can be passed to data plane operations.
+    ///
+    /// ### Return
+    /// * `Result<Metadata, PineconeError>` - `PineconeError::InvalidFilterError` if the filter is malformed,
+    ///   for example if a field name is empty, a number is not finite, or an `$in`, `$nin`, `$and` or `$or` operand is empty.
+    pub fn build(&self) -> Result<Metadata, PineconeError> {
+        let fields = match self {
+            Filter::Eq(field, value) => field_filter(field, "$eq", scalar(value)?)?,
+            Filter::Ne(field, value) => field_filter(field, "$ne", scalar(value)?)?,
+            Filter::Gt(field, value) => field_filter(field, "$gt", number(*value)?)?,
+            Filter::Gte(field, value) => field_filter(field, "$gte", number(*value)?)?,
+            Filter::Lt(field, value) => field_filter(field, "$lt", number(*value)?)?,
+            Filter::Lte(field, value) => field_filter(field, "$lte", number(*value)?)?,
+            Filter::In(field, values) => field_filter(field, "$in", list(field, values)?)?,
+            Filter::Nin(field, values) => field_filter(field, "$nin", list(field, values)?)?,
+            Filter::Exists(field, exists) => field_filter(
+                field,
+                "$exists",
+                Value {
+                    kind: Some(Kind::BoolValue(*exists)),
+                },
+            )?,
+            Filter::And(filters) => combine("$and", filters)?,
+            Filter::Or(filters) => combine("$or", filters)?,
+        };
+
+        Ok(Metadata { fields })
+    }
+}
+
+impl TryFrom<Filter> for Metadata {
+    type Error = PineconeError;
+
+    fn try_from(filter: Filter) -> Result<Self, Self::Error> {
+        filter.build()
+    }
+}
+
+// Helper function to create the error for a malformed filter
+fn invalid_filter(message: String) -> PineconeError {
+    PineconeError::InvalidFilterError { message }
+}
+
+// Builds `{field: {operator: value}}`
+fn field_filter(
+    field: &str,
+    operator: &str,
+    value: Value,
+) -> Result<BTreeMap<String, Value>, PineconeError> {
+    if field.is_empty() {
+        return Err(invalid_filter("Field name must not be empty".to_string()));
+    }
+    if field.starts_with('$') {
+        return Err(invalid_filter(format!(
+            "Field name \"{field}\" must not start with '$'"
+        )));
+    }
+
+    let condition = Metadata {
+        fields: BTreeMap::from([(operator.to_string(), value)]),
+    };
+    Ok(BTreeMap::from([(
+        field.to_string(),
+        Value {
+            kind: Some(Kind::StructValue(condition)),
+        },
+    )]))
+}
+
+// Builds `{operator: [filters]}`
+fn combine(operator: &str, filters: &[Filter]) -> Result<BTreeMap<String, Value>, PineconeError> {
+    if filters.is_empty() {
+        return Err(invalid_filter(format!(
+            "{operator} requires at least one filter"
+        )));
+    }
+
+    let values = filters
+        .iter()
+        .map(|filter| {
+            Ok(Value {
+                kind: Some(Kind::StructValue(filter.build()?)),
+            })
+        })
+        .collect::<Result<Vec<Value>, PineconeError>>()?;
+
+    Ok(BTreeMap::from([(
+        operator.to_string(),
+        Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        },
+    )]))
+}
+
+fn scalar(value: &FilterValue) -> Result<Value, PineconeError> {
+    if let FilterValue::Number(n) = value {
+        number(*n)?;
+    }
+    Ok(value.to_value())
+}
+
+fn number(value: f64) -> Result<Value, PineconeError> {
+    if !value.is_finite() {
+        return Err(invalid_filter(format!("Number {value} is not finite")));
+    }
+    Ok(Value {
+        kind: Some(Kind::NumberValue(value)),
+    })
+}
+
+// `$in` and `$nin` take a non-empty list of strings or of numbers
+fn list(field: &str, values: &[FilterValue]) -> Result<Value, PineconeError> {
+    if values.is_empty() {
+        return Err(invalid_filter(format!(
+            "List of values for field \"{field}\" must not be empty"
+        )));
+    }
+
+    let all_strings = values.iter().all(|v| matches!(v, FilterValue::String(_)));
+    let all_numbers = values.iter().all(|v| matches!(v, FilterValue::Number(_)));
+    if !all_strings && !all_numbers {
+        return Err(invalid_filter(format!(
+            "List of values for field \"{field}\" must contain only strings or only numbers"
+        )));
+    }
+
+    let values = values
+        .iter()
+        .map(scalar)
+        .collect::<Result<Vec<Value>, PineconeError>>()?;
+
+    Ok(Value {
+        kind: Some(Kind::ListValue(ListValue { values })),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio;
+
+    fn string(s: &str) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(s.to_string())),
+        }
+    }
+
+    fn number(n: f64) -> Value {
+        Value {
+            kind: Some(Kind::NumberValue(n)),
+        }
+    }
+
+    fn structure(fields: Vec<(&str, Value)>) -> Value {
+        Value {
+            kind: Some(Kind::StructValue(Metadata {
+                fields: fields
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v))
+                    .collect(),
+            })),
+        }
+    }
+
+    fn list(values: Vec<Value>) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_eq() {
+        let filter = Filter::eq("genre", "drama").build().unwrap();
+
+        let expected = Metadata {
+            fields: BTreeMap::from([(
+                "genre".to_string(),
+                structure(vec![("$eq", string("drama"))]),
+            )]),
+        };
+        assert_eq!(filter, expected);
+    }
+
+    #[tokio::test]
+    async fn test_build_comparisons() {
+        for (filter, operator) in [
+            (Filter::gt("year", 2020), "$gt"),
+            (Filter::gte("year", 2020), "$gte"),
+            (Filter::lt("year", 2020), "$lt"),
+            (Filter::lte("year", 2020), "$lte"),
+        ] {
+            let expected = Metadata {
+                fields: BTreeMap::from([(
+                    "year".to_string(),
+                    structure(vec![(operator, number(2020.0))]),
+                )]),
+            };
+            assert_eq!(filter.build().unwrap(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_nested() {
+        let filter = Filter::or(vec![
+            Filter::and(vec![
+                Filter::is_in("genre", ["comedy", "drama"]),
+                Filter::exists("rating", true),
+            ]),
+            Filter::ne("year", 2000),
+        ])
+        .build()
+        .unwrap();
+
+        let expected = Metadata {
+            fields: BTreeMap::from([(
+                "$or".to_string(),
+                list(vec![
+                    structure(vec![(
+                        "$and",
+                        list(vec![
+                            structure(vec![(
+                                "genre",
+                                structure(vec![(
+                                    "$in",
+                                    list(vec![string("comedy"), string("drama")]),
+                                )]),
+                            )]),
+                            structure(vec![(
+                                "rating",
+                                structure(vec![(
+                                    "$exists",
+                                    Value {
+                                        kind: Some(Kind::BoolValue(true)),
+                                    },
+                                )]),
+                            )]),
+                        ]),
+                    )]),
+                    structure(vec![("year", structure(vec![("$ne", number(2000.0))]))]),
+                ]),
+            )]),
+        };
+        assert_eq!(filter, expected);
+    }
+
+    #[tokio::test]
+    async fn test_build_invalid_filters() {
+        let invalid = [
+            Filter::eq("", "value"),
+            Filter::eq("$and", "value"),
+            Filter::gt("year", f64::NAN),
+            Filter::eq("year", f64::INFINITY),
+            Filter::is_in::<&str>("genre", []),
+            Filter::not_in("genre", vec![FilterValue::from("drama"), 1.into()]),
+            Filter::is_in("genre", [true, false]),
+            Filter::and(vec![]),
+            Filter::or(vec![Filter::eq("genre", "drama"), Filter::eq("", "drama")]),
+        ];
+
+        for filter in invalid {
+            let error = filter.build().expect_err("Expected filter to be invalid");
+            assert!(matches!(error, PineconeError::InvalidFilterError { .. }));
+        }
+    }
+}
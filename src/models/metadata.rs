@@ -0,0 +1,591 @@
+use super::{Kind, Metadata, Value};
+use crate::protos::{ScoredVector, Vector};
+use crate::utils::errors::PineconeError;
+use prost_types::ListValue;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value as JsonValue};
+
+// Largest integer magnitude that a f64 represents exactly: 2^53
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+/// Converts a value that implements `Serialize` to `Metadata`.
+///
+/// The value must serialize to a JSON object. Its fields may be strings, numbers, booleans, nulls,
+/// nested objects, or lists of strings, numbers, booleans and nulls.
+///
+/// ### Arguments
+/// * `value: &T` - The value to convert.
+///
+/// ### Return
+/// * `Result<Metadata, PineconeError>` - `PineconeError::InvalidMetadataError` if the value cannot be represented as metadata,
+///   for example an integer beyond the precision of a f64, or a list of objects.
+///
+/// ### Example
+/// ```
+/// use pinecone_sdk::models::{to_metadata, from_metadata, Metadata};
+/// use serde::{Deserialize, Serialize};
+/// # use pinecone_sdk::utils::errors::PineconeError;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Movie {
+///     genre: String,
+///     year: u32,
+///     tags: Vec<String>,
+/// }
+///
+/// # fn main() -> Result<(), PineconeError> {
+/// let movie = Movie {
+///     genre: "comedy".to_string(),
+///     year: 2020,
+///     tags: vec!["family".to_string()],
+/// };
+///
+/// let metadata: Metadata = to_metadata(&movie)?;
+/// let parsed: Movie = from_metadata(&metadata)?;
+/// assert_eq!(parsed, movie);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_metadata<T: Serialize + ?Sized>(value: &T) -> Result<Metadata, PineconeError> {
+    let json =
+        serde_json::to_value(value).map_err(|e| PineconeError::SerdeError { source: e.into() })?;
+    json_to_metadata(&json)
+}
+
+/// Deserializes `Metadata` into a value that implements `DeserializeOwned`.
+///
+/// Numbers that are integral and within the precision of a f64 are deserialized as integers.
+///
+/// ### Arguments
+/// * `metadata: &Metadata` - The metadata to deserialize.
+///
+/// ### Return
+/// * `Result<T, PineconeError>` - `PineconeError::SerdeError` if the metadata does not match `T`.
+pub fn from_metadata<T: DeserializeOwned>(metadata: &Metadata) -> Result<T, PineconeError> {
+    let json = metadata_to_json(metadata)?;
+    serde_json::from_value(json).map_err(|e| PineconeError::SerdeError { source: e.into() })
+}
+
+/// Converts a `serde_json::Value` to `Metadata`.
+///
+/// ### Arguments
+/// * `value: &serde_json::Value` - The value to convert. It must be a JSON object.
+///
+/// ### Return
+/// * `Result<Metadata, PineconeError>` - `PineconeError::InvalidMetadataError` if the value cannot be represented as metadata.
+pub fn json_to_metadata(value: &JsonValue) -> Result<Metadata, PineconeError> {
+    match value {
+        JsonValue::Object(map) => object_to_struct(map, "", Lists::Scalars),
+        _ => Err(invalid_metadata(format!(
+            "Metadata must be a JSON object, found {}",
+            json_type(value)
+        ))),
+    }
+}
+
+/// Converts `Metadata` to a `serde_json::Value`.
+///
+/// ### Arguments
+/// * `metadata: &Metadata` - The metadata to convert.
+///
+/// ### Return
+/// * `Result<serde_json::Value, PineconeError>` - `PineconeError::InvalidMetadataError` if the metadata contains a number that is not finite.
+pub fn metadata_to_json(metadata: &Metadata) -> Result<JsonValue, PineconeError> {
+    struct_to_object(metadata, "")
+}
+
+impl Vector {
+    /// Deserializes the metadata of the vector, or returns `None` if the vector has no metadata.
+    ///
+    /// ### Return
+    /// * `Result<Option<T>, PineconeError>`
+    pub fn deserialize_metadata<T: DeserializeOwned>(&self) -> Result<Option<T>, PineconeError> {
+        self.metadata.as_ref().map(from_metadata).transpose()
+    }
+}
+
+impl ScoredVector {
+    /// Deserializes the metadata of the vector, or returns `None` if the vector has no metadata.
+    ///
+    /// ### Return
+    /// * `Result<Option<T>, PineconeError>`
+    pub fn deserialize_metadata<T: DeserializeOwned>(&self) -> Result<Option<T>, PineconeError> {
+        self.metadata.as_ref().map(from_metadata).transpose()
+    }
+}
+
+/// Serializes and deserializes the optional metadata fields of the data plane messages as plain JSON objects, with
+/// `#[serde(with = "crate::models::metadata_json")]`.
+#[cfg(feature = "serde")]
+pub(crate) mod metadata_json {
+    use super::{json_to_metadata, metadata_to_json, Metadata};
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value as JsonValue;
+
+    pub(crate) fn serialize<S: Serializer>(
+        metadata: &Option<Metadata>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        metadata
+            .as_ref()
+            .map(metadata_to_json)
+            .transpose()
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Metadata>, D::Error> {
+        Option::<JsonValue>::deserialize(deserializer)?
+            .as_ref()
+            .map(json_to_metadata)
+            .transpose()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Serializes and deserializes the optional filter fields of the data plane messages as plain JSON objects, with
+/// `#[serde(with = "crate::models::filter_json")]`.
+///
+/// Unlike metadata, filters may contain lists of objects, such as the operands of `$and` and `$or`.
+#[cfg(feature = "serde")]
+pub(crate) mod filter_json {
+    use super::{json_to_filter, Metadata};
+    use serde::{de, Deserialize, Deserializer};
+    use serde_json::Value as JsonValue;
+
+    pub(crate) use super::metadata_json::serialize;
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Metadata>, D::Error> {
+        Option::<JsonValue>::deserialize(deserializer)?
+            .as_ref()
+            .map(json_to_filter)
+            .transpose()
+            .map_err(de::Error::custom)
+    }
+}
+
+// Converts a JSON filter to its `Metadata` representation, whose lists may contain objects and other lists
+#[cfg(feature = "serde")]
+fn json_to_filter(value: &JsonValue) -> Result<Metadata, PineconeError> {
+    match value {
+        JsonValue::Object(map) => object_to_struct(map, "", Lists::Any),
+        _ => Err(invalid_metadata(format!(
+            "Filter must be a JSON object, found {}",
+            json_type(value)
+        ))),
+    }
+}
+
+// The values that lists may contain: metadata lists only hold scalars, while filter lists hold any value
+#[derive(Clone, Copy, PartialEq)]
+enum Lists {
+    Scalars,
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    Any,
+}
+
+// Helper function to create the error for a value that cannot be represented
+fn invalid_metadata(message: String) -> PineconeError {
+    PineconeError::InvalidMetadataError { message }
+}
+
+// Joins a field path for error messages
+fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+fn json_type(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "a boolean",
+        JsonValue::Number(_) => "a number",
+        JsonValue::String(_) => "a string",
+        JsonValue::Array(_) => "an array",
+        JsonValue::Object(_) => "an object",
+    }
+}
+
+fn object_to_struct(
+    map: &Map<String, JsonValue>,
+    path: &str,
+    lists: Lists,
+) -> Result<Metadata, PineconeError> {
+    let fields = map
+        .iter()
+        .map(|(field, value)| {
+            let path = field_path(path, field);
+            Ok((field.clone(), json_to_value(value, &path, lists)?))
+        })
+        .collect::<Result<_, PineconeError>>()?;
+
+    Ok(Metadata { fields })
+}
+
+fn json_to_value(value: &JsonValue, path: &str, lists: Lists) -> Result<Value, PineconeError> {
+    let kind = match value {
+        JsonValue::Null => Kind::NullValue(0),
+        JsonValue::Bool(b) => Kind::BoolValue(*b),
+        JsonValue::Number(n) => Kind::NumberValue(number_to_f64(n, path)?),
+        JsonValue::String(s) => Kind::StringValue(s.clone()),
+        JsonValue::Array(values) => {
+            let values = values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let path = format!("{path}[{i}]");
+                    match value {
+                        JsonValue::Array(_) | JsonValue::Object(_) if lists == Lists::Scalars => Err(invalid_metadata(format!(
+                            "Field \"{path}\" is {}, but lists may only contain strings, numbers, booleans and nulls",
+                            json_type(value)
+                        ))),
+                        _ => json_to_value(value, &path, lists),
+                    }
+                })
+                .collect::<Result<_, PineconeError>>()?;
+            Kind::ListValue(ListValue { values })
+        }
+        JsonValue::Object(map) => Kind::StructValue(object_to_struct(map, path, lists)?),
+    };
+
+    Ok(Value { kind: Some(kind) })
+}
+
+// Integers are only accepted if a f64 represents them exactly
+fn number_to_f64(number: &Number, path: &str) -> Result<f64, PineconeError> {
+    let in_range = match (number.as_u64(), number.as_i64()) {
+        (Some(n), _) => n <= MAX_SAFE_INTEGER,
+        (None, Some(n)) => n.unsigned_abs() <= MAX_SAFE_INTEGER,
+        (None, None) => true,
+    };
+
+    match number.as_f64() {
+        Some(n) if in_range => Ok(n),
+        _ => Err(invalid_metadata(format!(
+            "Field \"{path}\" is {number}, which cannot be represented exactly as a f64"
+        ))),
+    }
+}
+
+fn struct_to_object(metadata: &Metadata, path: &str) -> Result<JsonValue, PineconeError> {
+    let map = metadata
+        .fields
+        .iter()
+        .map(|(field, value)| {
+            let path = field_path(path, field);
+            Ok((field.clone(), value_to_json(value, &path)?))
+        })
+        .collect::<Result<Map<String, JsonValue>, PineconeError>>()?;
+
+    Ok(JsonValue::Object(map))
+}
+
+fn value_to_json(value: &Value, path: &str) -> Result<JsonValue, PineconeError> {
+    let json = match &value.kind {
+        None | Some(Kind::NullValue(_)) => JsonValue::Null,
+        Some(Kind::BoolValue(b)) => JsonValue::Bool(*b),
+        Some(Kind::NumberValue(n)) => f64_to_number(*n, path)?,
+        Some(Kind::StringValue(s)) => JsonValue::String(s.clone()),
+        Some(Kind::ListValue(list)) => JsonValue::Array(
+            list.values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| value_to_json(value, &format!("{path}[{i}]")))
+                .collect::<Result<_, PineconeError>>()?,
+        ),
+        Some(Kind::StructValue(metadata)) => struct_to_object(metadata, path)?,
+    };
+
+    Ok(json)
+}
+
+// Integral numbers are converted back to JSON integers, so that they deserialize into integer types
+fn f64_to_number(number: f64, path: &str) -> Result<JsonValue, PineconeError> {
+    if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER as f64 {
+        return Ok(JsonValue::from(number as i64));
+    }
+
+    Number::from_f64(number)
+        .map(JsonValue::Number)
+        .ok_or_else(|| {
+            invalid_metadata(format!(
+                "Field \"{path}\" is {number}, which cannot be represented in JSON"
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use tokio;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Movie {
+        genre: String,
+        year: u32,
+        rating: f64,
+        released: bool,
+        tags: Vec<String>,
+        sequel: Option<String>,
+    }
+
+    fn movie() -> Movie {
+        Movie {
+            genre: "comedy".to_string(),
+            year: 2020,
+            rating: 7.5,
+            released: true,
+            tags: vec!["family".to_string(), "holiday".to_string()],
+            sequel: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_to_metadata() {
+        let metadata = to_metadata(&movie()).expect("Failed to convert to metadata");
+
+        assert_eq!(
+            metadata.fields.get("year"),
+            Some(&Value {
+                kind: Some(Kind::NumberValue(2020.0))
+            })
+        );
+        assert_eq!(
+            metadata.fields.get("sequel"),
+            Some(&Value {
+                kind: Some(Kind::NullValue(0))
+            })
+        );
+        assert_eq!(metadata.fields.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_metadata_round_trip() {
+        let metadata = to_metadata(&movie()).expect("Failed to convert to metadata");
+        let parsed: Movie = from_metadata(&metadata).expect("Failed to deserialize metadata");
+
+        assert_eq!(parsed, movie());
+    }
+
+    #[tokio::test]
+    async fn test_json_round_trip() {
+        let json = json!({
+            "genre": "comedy",
+            "year": -2020,
+            "rating": 7.5,
+            "tags": ["family", 1, true, null],
+            "nested": {"key": "value"},
+        });
+
+        let metadata = json_to_metadata(&json).expect("Failed to convert to metadata");
+        assert_eq!(metadata_to_json(&metadata).unwrap(), json);
+    }
+
+    #[tokio::test]
+    async fn test_to_metadata_not_object() {
+        let error = to_metadata(&vec!["value"]).expect_err("Expected conversion to fail");
+        assert!(matches!(error, PineconeError::InvalidMetadataError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_to_metadata_integer_out_of_range() {
+        assert!(json_to_metadata(&json!({ "id": MAX_SAFE_INTEGER })).is_ok());
+        assert!(json_to_metadata(&json!({ "id": -(MAX_SAFE_INTEGER as i64) })).is_ok());
+
+        let error = json_to_metadata(&json!({ "id": MAX_SAFE_INTEGER + 1 }))
+            .expect_err("Expected conversion to fail");
+        assert!(matches!(error, PineconeError::InvalidMetadataError { .. }));
+
+        let error = json_to_metadata(&json!({ "nested": { "id": u64::MAX } }))
+            .expect_err("Expected conversion to fail");
+        assert!(matches!(error, PineconeError::InvalidMetadataError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_to_metadata_nested_lists() {
+        for json in [
+            json!({ "tags": [{ "key": "value" }] }),
+            json!({ "tags": [["value"]] }),
+        ] {
+            let error = json_to_metadata(&json).expect_err("Expected conversion to fail");
+            assert!(matches!(error, PineconeError::InvalidMetadataError { .. }));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metadata_to_json_not_finite() {
+        let metadata = Metadata {
+            fields: BTreeMap::from([(
+                "rating".to_string(),
+                Value {
+                    kind: Some(Kind::NumberValue(f64::NAN)),
+                },
+            )]),
+        };
+
+        let error = metadata_to_json(&metadata).expect_err("Expected conversion to fail");
+        assert!(matches!(error, PineconeError::InvalidMetadataError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_from_metadata_mismatch() {
+        let metadata = to_metadata(&json!({ "genre": 1 })).unwrap();
+
+        let error =
+            from_metadata::<Movie>(&metadata).expect_err("Expected deserialization to fail");
+        assert!(matches!(error, PineconeError::SerdeError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_vector_deserialize_metadata() {
+        let vector = ScoredVector {
+            id: "id".to_string(),
+            metadata: Some(to_metadata(&movie()).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            vector.deserialize_metadata::<Movie>().unwrap(),
+            Some(movie())
+        );
+
+        let vector = Vector {
+            id: "id".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(vector.deserialize_metadata::<Movie>().unwrap(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_vector_serde() {
+        use crate::protos::SparseValues;
+
+        let vector = Vector {
+            id: "id".to_string(),
+            values: vec![1.0, 2.0],
+            sparse_values: Some(SparseValues {
+                indices: vec![0, 3],
+                values: vec![0.5, 0.25],
+            }),
+            metadata: Some(to_metadata(&json!({ "genre": "comedy", "year": 2020 })).unwrap()),
+        };
+
+        let value = serde_json::to_value(&vector).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "id": "id",
+                "values": [1.0, 2.0],
+                "sparseValues": { "indices": [0, 3], "values": [0.5, 0.25] },
+                "metadata": { "genre": "comedy", "year": 2020 },
+            })
+        );
+        assert_eq!(serde_json::from_value::<Vector>(value).unwrap(), vector);
+
+        let value = serde_json::to_value(Vector {
+            id: "id".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(value, json!({ "id": "id", "values": [] }));
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_query_response_deserialize() {
+        use crate::protos::QueryResponse;
+
+        let response: QueryResponse = serde_json::from_value(json!({
+            "matches": [
+                { "id": "id1", "score": 0.5, "metadata": { "genre": "comedy" } },
+                { "id": "id2", "score": 0.25, "values": [1.0, 2.0], "metadata": null },
+            ],
+            "namespace": "movies",
+            "usage": { "readUnits": 5 },
+        }))
+        .unwrap();
+
+        assert_eq!(response.namespace, "movies");
+        assert_eq!(response.usage.unwrap().read_units, Some(5));
+        assert_eq!(response.matches.len(), 2);
+        assert_eq!(
+            response.matches[0].metadata,
+            Some(to_metadata(&json!({ "genre": "comedy" })).unwrap())
+        );
+        assert_eq!(response.matches[1].values, vec![1.0, 2.0]);
+        assert_eq!(response.matches[1].metadata, None);
+
+        let error = serde_json::from_value::<QueryResponse>(json!({
+            "matches": [{ "id": "id1", "metadata": ["comedy"] }],
+        }))
+        .expect_err("Expected deserialization to fail");
+        assert!(error.to_string().contains("Metadata must be a JSON object"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_request_filter_serde() {
+        use crate::protos::{DeleteRequest, QueryRequest};
+
+        let filter = json!({
+            "$and": [
+                { "genre": { "$in": ["comedy", "drama"] } },
+                { "$or": [{ "year": { "$gte": 2020 } }, { "rating": 7.5 }] },
+            ]
+        });
+
+        let value = json!({
+            "namespace": "movies",
+            "topK": 10,
+            "filter": filter,
+            "includeValues": false,
+            "includeMetadata": true,
+            "vector": [1.0, 2.0],
+        });
+        let request: QueryRequest = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(request.top_k, 10);
+        assert!(request.filter.is_some());
+        assert_eq!(serde_json::to_value(&request).unwrap(), value);
+
+        let value = json!({ "deleteAll": false, "namespace": "movies", "filter": filter });
+        let request: DeleteRequest = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&request).unwrap(), value);
+
+        let error = serde_json::from_value::<QueryRequest>(json!({ "filter": ["comedy"] }))
+            .expect_err("Expected deserialization to fail");
+        assert!(error.to_string().contains("Filter must be a JSON object"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_describe_index_stats_response_serialize() {
+        use crate::protos::{DescribeIndexStatsResponse, NamespaceSummary};
+
+        let response = DescribeIndexStatsResponse {
+            namespaces: [("movies".to_string(), NamespaceSummary { vector_count: 3 })].into(),
+            dimension: 2,
+            index_fullness: 0.0,
+            total_vector_count: 3,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            json!({
+                "namespaces": { "movies": { "vectorCount": 3 } },
+                "dimension": 2,
+                "indexFullness": 0.0,
+                "totalVectorCount": 3,
+            })
+        );
+    }
+}
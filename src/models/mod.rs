@@ -19,19 +19,48 @@ pub use self::index_list::IndexList;
 mod wait_policy;
 pub use self::wait_policy::WaitPolicy;
 
+mod retry_policy;
+pub use self::retry_policy::RetryPolicy;
+
+#[cfg(feature = "data")]
+mod filter;
+#[cfg(feature = "data")]
+pub use self::filter::{Filter, FilterValue};
+
+#[cfg(feature = "data")]
+mod metadata;
+#[cfg(feature = "serde")]
+pub(crate) use self::metadata::{filter_json, metadata_json};
+#[cfg(feature = "data")]
+pub use self::metadata::{from_metadata, json_to_metadata, metadata_to_json, to_metadata};
+
+mod data_plane_transport;
+pub use self::data_plane_transport::DataPlaneTransport;
+
+mod tls_config;
+pub use self::tls_config::{ClientIdentity, TlsConfig, TlsRoots};
+
 mod embedding;
 pub use self::embedding::Embedding;
 
+#[cfg(feature = "data")]
+mod upsert_batch_response;
+#[cfg(feature = "data")]
+pub use self::upsert_batch_response::{FailedUpsertBatch, UpsertBatchResponse};
+
 pub use crate::openapi::models::{
-    index_model_status::State, serverless_spec::Cloud, CollectionList, CollectionModel,
-    ConfigureIndexRequest, ConfigureIndexRequestSpec, ConfigureIndexRequestSpecPod,
-    CreateCollectionRequest, DeletionProtection, EmbedRequestParameters, IndexModelSpec,
-    IndexModelStatus, IndexSpec, PodSpec, PodSpecMetadataConfig, ServerlessSpec,
+    error_response_error::Code as ErrorCode, index_model_status::State, serverless_spec::Cloud,
+    CollectionList, CollectionModel, ConfigureIndexRequest, ConfigureIndexRequestSpec,
+    ConfigureIndexRequestSpecPod, CreateCollectionRequest, DeletionProtection,
+    EmbedRequestParameters, IndexModelSpec, IndexModelStatus, IndexSpec, PodSpec,
+    PodSpecMetadataConfig, ServerlessSpec,
 };
 
+#[cfg(feature = "data")]
 pub use crate::protos::{
-    DescribeIndexStatsResponse, FetchResponse, ListResponse, QueryResponse, SparseValues,
-    UpdateResponse, UpsertResponse, Vector,
+    DescribeIndexStatsResponse, FetchResponse, ListResponse, QueryResponse, ScoredVector,
+    SparseValues, UpdateResponse, UpsertResponse, Vector,
 };
 
+#[cfg(feature = "data")]
 pub use prost_types::{value::Kind, Struct as Metadata, Value};
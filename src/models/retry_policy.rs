@@ -0,0 +1,102 @@
+use crate::utils::errors::{PineconeError, TransientErrorKind};
+use rand::Rng;
+use std::time::Duration;
+
+/// Defines the retry policy for requests that fail with a transient error.
+///
+/// The delay before retry `n` is `base_delay * 2^(n - 1)`, capped at `max_delay`, with up to
+/// `jitter` of it randomly subtracted to spread out concurrent retries.
+///
+/// Requests that are not safe to repeat, which create an index or a collection or configure an index,
+/// are only retried when they were rate limited (HTTP 429), the service was unavailable (HTTP 503) or
+/// the connection failed before the request was sent, as the server did not apply them. Other errors,
+/// such as HTTP 500, 502 or a timeout, are returned without retrying even if enabled in `retryable_errors`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one. A value of 1 disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub base_delay: Duration,
+    /// The maximum delay between two attempts.
+    pub max_delay: Duration,
+    /// The fraction of each delay, between 0.0 and 1.0, that is randomized.
+    pub jitter: f64,
+    /// The classes of transient errors that will be retried.
+    pub retryable_errors: Vec<TransientErrorKind>,
+}
+
+impl RetryPolicy {
+    /// Returns a policy that never retries failed requests.
+    pub fn disabled() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether a request that failed with `error` on attempt number `attempt` should be retried.
+    pub(crate) fn should_retry(&self, error: &PineconeError, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        match error.transient_kind() {
+            Some(kind) => self.retryable_errors.contains(&kind),
+            None => false,
+        }
+    }
+
+    /// Returns the policy for requests that are not safe to repeat, which only retries errors for which
+    /// the server did not apply the request.
+    #[cfg(feature = "control")]
+    pub(crate) fn non_idempotent(&self) -> RetryPolicy {
+        RetryPolicy {
+            retryable_errors: self
+                .retryable_errors
+                .iter()
+                .copied()
+                .filter(|kind| {
+                    matches!(
+                        kind,
+                        TransientErrorKind::RateLimited | TransientErrorKind::Unavailable
+                    )
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the delay to wait before the retry following attempt number `attempt`.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return backoff;
+        }
+
+        let factor = 1.0 - jitter * rand::thread_rng().gen::<f64>();
+        backoff.mul_f64(factor)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: 0.5,
+            retryable_errors: vec![
+                TransientErrorKind::RateLimited,
+                TransientErrorKind::ServerError,
+                TransientErrorKind::Unavailable,
+                TransientErrorKind::Timeout,
+            ],
+        }
+    }
+}
@@ -0,0 +1,41 @@
+/// The built-in root certificates that server certificates are verified against.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TlsRoots {
+    /// The root certificates of the platform's native certificate store
+    #[default]
+    Native,
+    /// The Mozilla root certificates bundled with the client
+    Webpki,
+}
+
+/// A client certificate and its private key, presented to servers that require mutual TLS.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ClientIdentity {
+    /// The PEM-encoded client certificate chain
+    pub certificate_pem: Vec<u8>,
+    /// The PEM-encoded private key of the client certificate
+    pub private_key_pem: Vec<u8>,
+}
+
+impl std::fmt::Debug for ClientIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientIdentity")
+            .field(
+                "certificate_pem",
+                &String::from_utf8_lossy(&self.certificate_pem),
+            )
+            .field("private_key_pem", &"<redacted>")
+            .finish()
+    }
+}
+
+/// TlsConfig : The TLS configuration of the control plane and data plane connections.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// The built-in root certificates to trust. Default is the platform's native certificate store.
+    pub roots: TlsRoots,
+    /// Additional PEM-encoded root certificates to trust, such as the certificate of a private CA
+    pub extra_root_certificates_pem: Vec<Vec<u8>>,
+    /// The client identity to present to servers that require mutual TLS
+    pub client_identity: Option<ClientIdentity>,
+}
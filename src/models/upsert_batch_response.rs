@@ -0,0 +1,34 @@
+use crate::utils::errors::PineconeError;
+
+/// UpsertBatchResponse : The result of upserting vectors in batches.
+#[derive(Debug, Default)]
+pub struct UpsertBatchResponse {
+    /// The total number of vectors upserted by the batches that succeeded.
+    pub upserted_count: u32,
+    /// The batches that failed to be upserted.
+    pub failed_batches: Vec<FailedUpsertBatch>,
+}
+
+impl UpsertBatchResponse {
+    /// Returns true if every batch was upserted.
+    pub fn is_success(&self) -> bool {
+        self.failed_batches.is_empty()
+    }
+
+    /// Returns the IDs of all vectors that failed to be upserted.
+    pub fn failed_ids(&self) -> Vec<&str> {
+        self.failed_batches
+            .iter()
+            .flat_map(|batch| batch.ids.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+/// FailedUpsertBatch : A batch of vectors that failed to be upserted.
+#[derive(Debug)]
+pub struct FailedUpsertBatch {
+    /// The IDs of the vectors in the batch.
+    pub ids: Vec<String>,
+    /// The error returned for the batch.
+    pub error: PineconeError,
+}
@@ -4,7 +4,7 @@ use std::time::Duration;
 use crate::openapi::apis::manage_indexes_api;
 use crate::openapi::models::CreateIndexRequest;
 use crate::pinecone::PineconeClient;
-use crate::utils::errors::PineconeError;
+use crate::utils::errors::{ControlPlaneEndpoint, PineconeError};
 
 use crate::models::{
     Cloud, CollectionList, CollectionModel, ConfigureIndexRequest, ConfigureIndexRequestSpec,
@@ -51,6 +51,7 @@ impl PineconeClient {
     /// # }
     /// ```
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.name = name, dimension = dimension, error.kind = tracing::field::Empty)))]
     pub async fn create_serverless_index(
         &self,
         name: &str,
@@ -78,10 +79,21 @@ impl PineconeClient {
             spec: Some(Box::new(create_index_request_spec)),
         };
 
+        let endpoint = ControlPlaneEndpoint::CreateIndex {
+            from_collection: false,
+        };
+
         // make openAPI call
-        let res = manage_indexes_api::create_index(&self.openapi_config, create_index_request)
-            .await
-            .map_err(PineconeError::from)?;
+        let res = self
+            .call_non_idempotent("create_serverless_index", || {
+                let request = create_index_request.clone();
+                async move {
+                    manage_indexes_api::create_index(&self.openapi_config, request)
+                        .await
+                        .map_err(|e| PineconeError::from_openapi(e, endpoint))
+                }
+            })
+            .await?;
 
         // poll index status
         match self.handle_poll_index(name, timeout).await {
@@ -142,6 +154,7 @@ impl PineconeClient {
     /// # }
     /// ```
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.name = name, dimension = dimension, error.kind = tracing::field::Empty)))]
     pub async fn create_pod_index(
         &self,
         name: &str,
@@ -183,10 +196,21 @@ impl PineconeClient {
             spec: Some(Box::new(spec)),
         };
 
+        let endpoint = ControlPlaneEndpoint::CreateIndex {
+            from_collection: source_collection.is_some(),
+        };
+
         // make openAPI call
-        let res = manage_indexes_api::create_index(&self.openapi_config, create_index_request)
-            .await
-            .map_err(PineconeError::from)?;
+        let res = self
+            .call_non_idempotent("create_pod_index", || {
+                let request = create_index_request.clone();
+                async move {
+                    manage_indexes_api::create_index(&self.openapi_config, request)
+                        .await
+                        .map_err(|e| PineconeError::from_openapi(e, endpoint))
+                }
+            })
+            .await?;
 
         // poll index status
         match self.handle_poll_index(name, timeout).await {
@@ -266,11 +290,16 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.name = name, error.kind = tracing::field::Empty)))]
     pub async fn describe_index(&self, name: &str) -> Result<IndexModel, PineconeError> {
         // make openAPI call
-        let res = manage_indexes_api::describe_index(&self.openapi_config, name)
-            .await
-            .map_err(PineconeError::from)?;
+        let res = self
+            .call("describe_index", || async move {
+                manage_indexes_api::describe_index(&self.openapi_config, name)
+                    .await
+                    .map_err(|e| PineconeError::from_openapi(e, ControlPlaneEndpoint::Index))
+            })
+            .await?;
 
         Ok(res.into())
     }
@@ -297,11 +326,16 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(error.kind = tracing::field::Empty)))]
     pub async fn list_indexes(&self) -> Result<IndexList, PineconeError> {
         // make openAPI call
-        let res = manage_indexes_api::list_indexes(&self.openapi_config)
-            .await
-            .map_err(PineconeError::from)?;
+        let res = self
+            .call("list_indexes", || async move {
+                manage_indexes_api::list_indexes(&self.openapi_config)
+                    .await
+                    .map_err(PineconeError::from)
+            })
+            .await?;
 
         Ok(res.into())
     }
@@ -339,6 +373,7 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.name = name, error.kind = tracing::field::Empty)))]
     pub async fn configure_index(
         &self,
         name: &str,
@@ -380,13 +415,18 @@ impl PineconeClient {
         };
 
         // make openAPI call
-        let res = manage_indexes_api::configure_index(
-            &self.openapi_config,
-            name,
-            configure_index_request,
-        )
-        .await
-        .map_err(PineconeError::from)?;
+        let res = self
+            .call_non_idempotent("configure_index", || {
+                let request = configure_index_request.clone();
+                async move {
+                    manage_indexes_api::configure_index(&self.openapi_config, name, request)
+                        .await
+                        .map_err(|e| {
+                            PineconeError::from_openapi(e, ControlPlaneEndpoint::ConfigureIndex)
+                        })
+                }
+            })
+            .await?;
 
         Ok(res.into())
     }
@@ -412,11 +452,17 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.name = name, error.kind = tracing::field::Empty)))]
     pub async fn delete_index(&self, name: &str) -> Result<(), PineconeError> {
         // make openAPI call
-        manage_indexes_api::delete_index(&self.openapi_config, name)
-            .await
-            .map_err(PineconeError::from)?;
+        self.call("delete_index", || async move {
+            manage_indexes_api::delete_index(&self.openapi_config, name)
+                .await
+                .map_err(|e| PineconeError::from_openapi(e, ControlPlaneEndpoint::Index))
+        })
+        .await?;
+
+        self.index_host_cache.invalidate(name);
 
         Ok(())
     }
@@ -444,6 +490,7 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(collection.name = name, index.name = source, error.kind = tracing::field::Empty)))]
     pub async fn create_collection(
         &self,
         name: &str,
@@ -455,10 +502,18 @@ impl PineconeClient {
         };
 
         // make openAPI call
-        let res =
-            manage_indexes_api::create_collection(&self.openapi_config, create_collection_request)
-                .await
-                .map_err(PineconeError::from)?;
+        let res = self
+            .call_non_idempotent("create_collection", || {
+                let request = create_collection_request.clone();
+                async move {
+                    manage_indexes_api::create_collection(&self.openapi_config, request)
+                        .await
+                        .map_err(|e| {
+                            PineconeError::from_openapi(e, ControlPlaneEndpoint::CreateCollection)
+                        })
+                }
+            })
+            .await?;
 
         Ok(res)
     }
@@ -485,10 +540,15 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(collection.name = name, error.kind = tracing::field::Empty)))]
     pub async fn describe_collection(&self, name: &str) -> Result<CollectionModel, PineconeError> {
-        let res = manage_indexes_api::describe_collection(&self.openapi_config, name)
-            .await
-            .map_err(PineconeError::from)?;
+        let res = self
+            .call("describe_collection", || async move {
+                manage_indexes_api::describe_collection(&self.openapi_config, name)
+                    .await
+                    .map_err(|e| PineconeError::from_openapi(e, ControlPlaneEndpoint::Collection))
+            })
+            .await?;
 
         Ok(res)
     }
@@ -514,11 +574,16 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(error.kind = tracing::field::Empty)))]
     pub async fn list_collections(&self) -> Result<CollectionList, PineconeError> {
         // make openAPI call
-        let res = manage_indexes_api::list_collections(&self.openapi_config)
-            .await
-            .map_err(PineconeError::from)?;
+        let res = self
+            .call("list_collections", || async move {
+                manage_indexes_api::list_collections(&self.openapi_config)
+                    .await
+                    .map_err(PineconeError::from)
+            })
+            .await?;
 
         Ok(res)
     }
@@ -544,25 +609,35 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(collection.name = name, error.kind = tracing::field::Empty)))]
     pub async fn delete_collection(&self, name: &str) -> Result<(), PineconeError> {
         // make openAPI call
-        manage_indexes_api::delete_collection(&self.openapi_config, name)
-            .await
-            .map_err(PineconeError::from)?;
+        self.call("delete_collection", || async move {
+            manage_indexes_api::delete_collection(&self.openapi_config, name)
+                .await
+                .map_err(|e| PineconeError::from_openapi(e, ControlPlaneEndpoint::Collection))
+        })
+        .await?;
 
         Ok(())
     }
 }
 
+// The tests predate the newer clippy lints that CI runs with `-D warnings`
 #[cfg(test)]
+#[allow(clippy::useless_vec, clippy::let_unit_value)]
 mod tests {
     use super::*;
+    use crate::models::RetryPolicy;
     use crate::openapi::{
         self,
         models::{self, collection_model::Status},
     };
     use crate::pinecone::PineconeClientConfig;
+    use crate::utils::metrics::tests::RecordingObserver;
+    use crate::utils::metrics::OperationOutcome;
     use httpmock::prelude::*;
+    use std::sync::Arc;
     use tokio;
 
     #[tokio::test]
@@ -1004,6 +1079,115 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_describe_index_retry() -> Result<(), PineconeError> {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/indexes/index-name");
+            then.status(503);
+        });
+
+        let config = PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            control_plane_host: Some(server.base_url()),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pinecone = config.client().expect("Failed to create Pinecone instance");
+
+        let describe_index_response = pinecone
+            .describe_index("index-name")
+            .await
+            .expect_err("Expected describe_index to return an error");
+
+        assert!(matches!(
+            describe_index_response,
+            PineconeError::ServiceUnavailableError { .. }
+        ));
+        mock.assert_hits(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_describe_index_metrics_observer() -> Result<(), PineconeError> {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/indexes/index-name");
+            then.status(503);
+        });
+
+        let observer = Arc::new(RecordingObserver::default());
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .control_plane_host(server.base_url())
+            .retry_policy(RetryPolicy {
+                max_attempts: 2,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            })
+            .metrics_observer(observer.clone())
+            .client()
+            .expect("Failed to create Pinecone instance");
+
+        let _ = pinecone.describe_index("index-name").await;
+
+        // retries are reported as a single operation
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec![(
+                "describe_index",
+                OperationOutcome::TransientError,
+                None,
+                None
+            )]
+        );
+        mock.assert_hits(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_describe_index_no_retry_client_error() -> Result<(), PineconeError> {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/indexes/index-name");
+            then.status(401);
+        });
+
+        let config = PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            control_plane_host: Some(server.base_url()),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pinecone = config.client().expect("Failed to create Pinecone instance");
+
+        let describe_index_response = pinecone
+            .describe_index("index-name")
+            .await
+            .expect_err("Expected describe_index to return an error");
+
+        assert!(matches!(
+            describe_index_response,
+            PineconeError::UnauthorizedError { .. }
+        ));
+        mock.assert_hits(1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_list_indexes() -> Result<(), PineconeError> {
         let server = MockServer::start();
@@ -2151,6 +2335,77 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_create_collection_no_retry_server_error() -> Result<(), PineconeError> {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/collections");
+            then.status(500);
+        });
+
+        let config = PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            control_plane_host: Some(server.base_url()),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pinecone = config.client().expect("Failed to create Pinecone instance");
+
+        let create_collection_response = pinecone
+            .create_collection("collection-name", "index1")
+            .await
+            .expect_err("Expected create_collection to return an error");
+
+        // the server may have created the collection, so the request is not repeated
+        assert!(matches!(
+            create_collection_response,
+            PineconeError::InternalServerError { .. }
+        ));
+        mock.assert_hits(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_collection_retry_unavailable() -> Result<(), PineconeError> {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/collections");
+            then.status(503);
+        });
+
+        let config = PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            control_plane_host: Some(server.base_url()),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pinecone = config.client().expect("Failed to create Pinecone instance");
+
+        let create_collection_response = pinecone
+            .create_collection("collection-name", "index1")
+            .await
+            .expect_err("Expected create_collection to return an error");
+
+        assert!(matches!(
+            create_collection_response,
+            PineconeError::ServiceUnavailableError { .. }
+        ));
+        mock.assert_hits(3);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_describe_collection() -> Result<(), PineconeError> {
         let server = MockServer::start();
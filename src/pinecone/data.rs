@@ -1,18 +1,50 @@
+use crate::models::DataPlaneTransport;
+#[cfg(feature = "rustls")]
+use crate::models::TlsRoots;
 use crate::pinecone::PineconeClient;
 use crate::protos::vector_service_client::VectorServiceClient;
 use crate::utils::errors::PineconeError;
+use crate::utils::host_cache::IndexHostCache;
+use crate::utils::metrics::{observe, observe_rejected, MetricsObserver, OperationUsage};
+use crate::utils::retry::with_retry;
+use crate::utils::telemetry;
+use crate::utils::validation::VectorValidator;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use once_cell::sync::Lazy;
+use prost::Message;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
 use tonic::metadata::{Ascii, MetadataValue as TonicMetadataVal};
 use tonic::service::interceptor::InterceptedService;
 use tonic::service::Interceptor;
 use tonic::transport::Channel;
+#[cfg(feature = "rustls")]
+use tonic::transport::{Certificate, ClientTlsConfig, Identity};
 use tonic::{Request, Status};
 
 use crate::models::{
-    DescribeIndexStatsResponse, FetchResponse, ListResponse, Metadata, Namespace, QueryResponse,
-    SparseValues, UpdateResponse, UpsertResponse, Vector,
+    DescribeIndexStatsResponse, FailedUpsertBatch, FetchResponse, IndexModel, ListResponse,
+    Metadata, Namespace, QueryResponse, RetryPolicy, SparseValues, UpdateResponse,
+    UpsertBatchResponse, UpsertResponse, Vector,
 };
 use crate::protos;
+#[cfg(feature = "serde")]
+use rest::RestClient;
+use rest::RestRequest;
+pub use vector_store::VectorStore;
+
+mod rest;
+mod vector_store;
+
+/// The maximum size of an upsert request accepted by Pinecone, in bytes.
+pub const MAX_UPSERT_REQUEST_BYTES: usize = 2 * 1024 * 1024;
+
+/// The default number of vectors per batch for `Index::upsert_batched`.
+pub const DEFAULT_UPSERT_BATCH_SIZE: usize = 100;
+
+/// The default number of batches sent concurrently by `Index::upsert_batched`.
+pub const DEFAULT_UPSERT_CONCURRENCY: usize = 4;
 
 #[derive(Debug, Clone)]
 struct ApiKeyInterceptor {
@@ -27,20 +59,210 @@ impl Interceptor for ApiKeyInterceptor {
                 .metadata_mut()
                 .insert("api-key", self.api_token.clone());
         }
+        telemetry::inject_grpc_context(request.metadata_mut());
         Ok(request)
     }
 }
 
+type DataPlaneClient = VectorServiceClient<InterceptedService<Channel, ApiKeyInterceptor>>;
+
+/// The connection to the data plane of an index, over the transport the client is configured with.
+#[derive(Debug, Clone)]
+enum DataPlaneConnection {
+    Grpc(DataPlaneClient),
+    #[cfg(feature = "serde")]
+    Rest(RestClient),
+}
+
 /// A client for interacting with a Pinecone index.
-#[derive(Debug)]
+///
+/// `Index` is cheap to clone: clones share the same underlying connection, and can be used to send
+/// concurrent requests from multiple tasks.
+#[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct Index {
     /// The name of the index.
     host: String,
-    connection: VectorServiceClient<InterceptedService<Channel, ApiKeyInterceptor>>,
+    connection: DataPlaneConnection,
+    retry_policy: RetryPolicy,
+    /// The name of the index, if it was targeted by name.
+    name: Option<String>,
+    host_cache: IndexHostCache,
+    /// The validator for vectors and queries, if validation is enabled.
+    validator: Option<VectorValidator>,
+    /// The deadline of each operation, including retries.
+    deadline: Option<Duration>,
+    /// The observer of operations, if any.
+    metrics_observer: Option<Arc<dyn MetricsObserver>>,
 }
 
 impl Index {
+    /// Enables client-side validation of vectors and queries against the index description, before they are sent.
+    ///
+    /// Upserted, updated and query vectors are checked for a dimension that does not match the index, sparse values
+    /// with unsorted or duplicated indices, with as many values as indices, or on an index that does not use the
+    /// dotproduct metric, an empty ID, and metadata larger than 40 KB once serialized as JSON. Invalid vectors are
+    /// reported with `PineconeError::ValidationError`, which names the offending vector ID and field.
+    ///
+    /// `PineconeClient::index_with_validation` targets an index by name and describes it for the validator. This
+    /// method is for callers that already have the description of the index.
+    ///
+    /// ### Arguments
+    /// * `index: &IndexModel` - The description of the index, as returned by `describe_index`.
+    ///
+    /// ### Return
+    /// * `Index`
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let description = pinecone.describe_index("index-name").await?;
+    /// let index = pinecone.index(&description.host).await?.with_validation(&description);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_validation(mut self, index: &IndexModel) -> Self {
+        self.validator = Some(VectorValidator::new(index));
+        self
+    }
+
+    /// Returns a copy of the index whose operations must complete within `deadline`, including retries.
+    ///
+    /// The remaining time is sent to the server with the `grpc-timeout` header of each gRPC attempt, and operations
+    /// that do not complete in time fail with `PineconeError::DataPlaneDeadlineExceededError`. Dropping the
+    /// future of an operation cancels it. The deadline overrides the client-wide `default_deadline`.
+    ///
+    /// ### Arguments
+    /// * `deadline: Duration` - The maximum duration of each operation.
+    ///
+    /// ### Return
+    /// * `Index`
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use pinecone_sdk::models::Namespace;
+    /// use std::time::Duration;
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let index = pinecone.index("index-host").await?;
+    ///
+    /// let response = index
+    ///     .with_deadline(Duration::from_millis(500))
+    ///     .fetch(&["1", "2"], &Namespace::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_deadline(&self, deadline: Duration) -> Self {
+        Index {
+            deadline: Some(deadline),
+            ..self.clone()
+        }
+    }
+
+    // Helper function to report an operation that failed before its request was sent
+    fn reject(&self, name: &'static str, error: PineconeError) -> PineconeError {
+        telemetry::record_error(&error);
+        observe_rejected(
+            self.metrics_observer.as_ref(),
+            name,
+            Some(&self.host),
+            &error,
+        );
+        error
+    }
+
+    // Helper function to send a request to the index, retrying transient errors until the deadline
+    async fn call<Req, Res, F, Fut>(
+        &self,
+        name: &'static str,
+        request: Req,
+        operation: F,
+    ) -> Result<Res, PineconeError>
+    where
+        Req: Clone + RestRequest<Response = Res>,
+        Res: OperationUsage,
+        F: Fn(DataPlaneClient, Request<Req>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Res>, Status>>,
+    {
+        // a deadline too far in the future to be represented, such as `Duration::MAX`, never expires
+        let expires_at = self
+            .deadline
+            .and_then(|deadline| tokio::time::Instant::now().checked_add(deadline));
+
+        let operation = &operation;
+        let request = &request;
+        let attempts = with_retry(&self.retry_policy, || async move {
+            let timeout = expires_at.map(|expires_at| {
+                expires_at.saturating_duration_since(tokio::time::Instant::now())
+            });
+
+            match &self.connection {
+                DataPlaneConnection::Grpc(client) => {
+                    let mut request = Request::new(request.clone());
+                    if let Some(timeout) = timeout {
+                        request.set_timeout(timeout);
+                    }
+
+                    operation(client.clone(), request)
+                        .await
+                        .map(tonic::Response::into_inner)
+                        .map_err(PineconeError::from)
+                }
+                #[cfg(feature = "serde")]
+                DataPlaneConnection::Rest(client) => client.send(request, timeout).await,
+            }
+        });
+
+        let attempts = async {
+            match (expires_at, self.deadline) {
+                (Some(expires_at), Some(deadline)) => tokio::time::timeout_at(expires_at, attempts)
+                    .await
+                    .unwrap_or_else(|_| {
+                        let error = PineconeError::DataPlaneDeadlineExceededError {
+                            status: Status::deadline_exceeded(format!(
+                                "Deadline of {deadline:?} expired"
+                            )),
+                        };
+                        telemetry::record_error(&error);
+                        Err(error)
+                    }),
+                _ => attempts.await,
+            }
+        };
+
+        let res = observe(
+            self.metrics_observer.as_ref(),
+            name,
+            Some(&self.host),
+            attempts,
+        )
+        .await;
+
+        // the index may have been deleted or recreated under the same name with a new host
+        if let (
+            Err(
+                PineconeError::DataPlaneNotFoundError { .. }
+                | PineconeError::DataPlaneUnavailableError { .. },
+            ),
+            Some(name),
+        ) = (&res, &self.name)
+        {
+            self.host_cache.invalidate(name);
+        }
+
+        res
+    }
+
     /// The upsert operation writes vectors into a namespace.
     /// If a new value is upserted for an existing vector id, it will overwrite the previous value.
     ///
@@ -60,7 +282,7 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// let vectors = [Vector {
     ///     id: "vector-id".to_string(),
@@ -74,8 +296,30 @@ impl Index {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, batch_size = vectors.len(), error.kind = tracing::field::Empty)))]
     pub async fn upsert(
-        &mut self,
+        &self,
+        vectors: &[Vector],
+        namespace: &Namespace,
+    ) -> Result<UpsertResponse, PineconeError> {
+        self.validate_vectors(vectors)
+            .map_err(|e| self.reject("upsert", e))?;
+        self.send_upsert(vectors, namespace).await
+    }
+
+    // Helper function to validate vectors to upsert, if validation is enabled
+    fn validate_vectors(&self, vectors: &[Vector]) -> Result<(), PineconeError> {
+        match &self.validator {
+            Some(validator) => vectors
+                .iter()
+                .try_for_each(|vector| validator.validate_vector(vector)),
+            None => Ok(()),
+        }
+    }
+
+    // Helper function to call upsert operation
+    async fn send_upsert(
+        &self,
         vectors: &[Vector],
         namespace: &Namespace,
     ) -> Result<UpsertResponse, PineconeError> {
@@ -84,12 +328,94 @@ impl Index {
             namespace: namespace.name.clone(),
         };
 
-        let response = self
-            .connection
-            .upsert(request)
-            .await
-            .map_err(|e| PineconeError::DataPlaneError { status: e })?
-            .into_inner();
+        self.call("upsert", request, |mut client, request| async move {
+            client.upsert(request).await
+        })
+        .await
+    }
+
+    /// The upsert_batched operation writes vectors into a namespace in batches, sending several batches concurrently.
+    /// Vectors are split into batches of at most `batch_size` vectors, and at most `MAX_UPSERT_REQUEST_BYTES` bytes once encoded.
+    ///
+    /// A batch that fails does not stop the other batches from being upserted. The response contains the total number of
+    /// vectors upserted, and the IDs of the vectors in each failed batch along with the error, so that only those can be upserted again.
+    ///
+    /// ### Arguments
+    /// * `vectors: &[Vector]` - A list of vectors to upsert.
+    /// * `namespace: &Namespace` - The namespace to upsert vectors into. Default is "".
+    /// * `batch_size: Option<usize>` - The maximum number of vectors per batch. Default is 100.
+    /// * `max_concurrency: Option<usize>` - The maximum number of batches sent concurrently. Default is 4.
+    ///
+    /// ### Return
+    /// * `Result<UpsertBatchResponse, PineconeError>`
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use pinecone_sdk::models::{Namespace, UpsertBatchResponse, Vector};
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let index = pinecone.index("index-host").await?;
+    ///
+    /// let vectors: Vec<Vector> = (0..1000).map(|i| Vector {
+    ///     id: format!("vector-{i}"),
+    ///     values: vec![1.0, 2.0, 3.0, 4.0],
+    ///     sparse_values: None,
+    ///     metadata: None,
+    /// }).collect();
+    ///
+    /// // Upsert vectors into the namespace "namespace" in batches of 200
+    /// let response: UpsertBatchResponse = index.upsert_batched(&vectors, &"namespace".into(), Some(200), None).await?;
+    /// if !response.is_success() {
+    ///     println!("Failed to upsert {:?}", response.failed_ids());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, vector_count = vectors.len(), batch_size = batch_size.unwrap_or(DEFAULT_UPSERT_BATCH_SIZE), error.kind = tracing::field::Empty)))]
+    pub async fn upsert_batched(
+        &self,
+        vectors: &[Vector],
+        namespace: &Namespace,
+        batch_size: Option<usize>,
+        max_concurrency: Option<usize>,
+    ) -> Result<UpsertBatchResponse, PineconeError> {
+        let batch_size = batch_size.unwrap_or(DEFAULT_UPSERT_BATCH_SIZE);
+        let max_concurrency = max_concurrency.unwrap_or(DEFAULT_UPSERT_CONCURRENCY);
+        if batch_size == 0 || max_concurrency == 0 {
+            return Err(self.reject(
+                "upsert",
+                PineconeError::InvalidConfigurationError {
+                    message: "Batch size and max concurrency must be greater than 0".to_string(),
+                },
+            ));
+        }
+
+        self.validate_vectors(vectors)
+            .map_err(|e| self.reject("upsert", e))?;
+
+        let batches =
+            split_upsert_batches(vectors, namespace, batch_size, MAX_UPSERT_REQUEST_BYTES);
+
+        let results = stream::iter(batches)
+            .map(|batch| async move { (batch, self.send_upsert(batch, namespace).await) })
+            .buffer_unordered(max_concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut response = UpsertBatchResponse::default();
+        for (batch, result) in results {
+            match result {
+                Ok(res) => response.upserted_count += res.upserted_count,
+                Err(error) => response.failed_batches.push(FailedUpsertBatch {
+                    ids: batch.iter().map(|v| v.id.clone()).collect(),
+                    error,
+                }),
+            }
+        }
 
         Ok(response)
     }
@@ -114,15 +440,16 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// // List all vectors in the namespace "namespace"
     /// let response: Result<ListResponse, PineconeError> = index.list(&"namespace".into(), None, None, None).await;
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, limit = limit, read_units = tracing::field::Empty, error.kind = tracing::field::Empty)))]
     pub async fn list(
-        &mut self,
+        &self,
         namespace: &Namespace,
         prefix: Option<&str>,
         limit: Option<u32>,
@@ -135,14 +462,120 @@ impl Index {
             pagination_token: pagination_token.map(|s| s.to_string()),
         };
 
-        let response = self
-            .connection
-            .list(request)
-            .await
-            .map_err(|e| PineconeError::DataPlaneError { status: e })?
-            .into_inner();
+        self.call("list", request, |mut client, request| async move {
+            client.list(request).await
+        })
+        .await
+        .inspect(|response| telemetry::record_usage(response.usage.as_ref()))
+    }
 
-        Ok(response)
+    /// The list_pages operation lists the vector IDs of a namespace page by page, following the pagination token until all
+    /// pages have been returned. Pages are requested lazily as the stream is polled, so dropping the stream stops the listing.
+    ///
+    /// ### Arguments
+    /// * `namespace: &Namespace` - The namespace to list vectors from. Default is "".
+    /// * `prefix: Option<&str>` - The vector IDs to list, will list all vectors with IDs that have a matching prefix. Default is empty string.
+    ///
+    /// ### Return
+    /// * `BoxStream<'static, Result<ListResponse, PineconeError>>`
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use pinecone_sdk::models::{Namespace, ListResponse};
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let index = pinecone.index("index-host").await?;
+    ///
+    /// // List all pages of vectors in the namespace "namespace"
+    /// let pages: Vec<ListResponse> = index.list_pages(&"namespace".into(), None).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_pages(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+    ) -> BoxStream<'static, Result<ListResponse, PineconeError>> {
+        let index = self.clone();
+        let namespace = namespace.clone();
+        let prefix = prefix.map(|s| s.to_string());
+
+        // `None` once the last page has been returned, otherwise the token of the next page to request
+        let initial_state: Option<Option<String>> = Some(None);
+
+        stream::try_unfold(initial_state, move |state| {
+            let index = index.clone();
+            let namespace = namespace.clone();
+            let prefix = prefix.clone();
+            async move {
+                let Some(pagination_token) = state else {
+                    return Ok(None);
+                };
+
+                let page = index
+                    .list(
+                        &namespace,
+                        prefix.as_deref(),
+                        None,
+                        pagination_token.as_deref(),
+                    )
+                    .await?;
+
+                let next_state = next_pagination_token(&page).map(Some);
+                Ok(Some((page, next_state)))
+            }
+        })
+        .boxed()
+    }
+
+    /// The list_all operation lists the IDs of all vectors in a namespace, following the pagination token until all
+    /// pages have been returned or `limit` IDs have been listed. Pages are requested lazily as the stream is polled,
+    /// so dropping the stream stops the listing.
+    ///
+    /// ### Arguments
+    /// * `namespace: &Namespace` - The namespace to list vectors from. Default is "".
+    /// * `prefix: Option<&str>` - The vector IDs to list, will list all vectors with IDs that have a matching prefix. Default is empty string.
+    /// * `limit: Option<usize>` - The maximum number of vector IDs to return in total. Default is no limit.
+    ///
+    /// ### Return
+    /// * `BoxStream<'static, Result<String, PineconeError>>`
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use pinecone_sdk::models::Namespace;
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let index = pinecone.index("index-host").await?;
+    ///
+    /// // List the IDs of all vectors with the prefix "doc1#" in the namespace "namespace"
+    /// let mut ids = index.list_all(&"namespace".into(), Some("doc1#"), None);
+    /// while let Some(id) = ids.try_next().await? {
+    ///     println!("{id}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+        limit: Option<usize>,
+    ) -> BoxStream<'static, Result<String, PineconeError>> {
+        self.list_pages(namespace, prefix)
+            .map_ok(|page| stream::iter(page.vectors.into_iter().map(|item| Ok(item.id))))
+            .try_flatten()
+            .take(limit.unwrap_or(usize::MAX))
+            .boxed()
     }
 
     /// The describe_index_stats operation returns statistics about the index.
@@ -163,7 +596,7 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// // Construct a metadata filter
     /// let mut fields = BTreeMap::new();
@@ -175,35 +608,34 @@ impl Index {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, error.kind = tracing::field::Empty)))]
     pub async fn describe_index_stats(
-        &mut self,
+        &self,
         filter: Option<Metadata>,
     ) -> Result<DescribeIndexStatsResponse, PineconeError> {
         let request = protos::DescribeIndexStatsRequest { filter };
 
-        let response = self
-            .connection
-            .describe_index_stats(request)
-            .await
-            .map_err(|e| PineconeError::DataPlaneError { status: e })?
-            .into_inner();
-
-        Ok(response)
+        self.call(
+            "describe_index_stats",
+            request,
+            |mut client, request| async move { client.describe_index_stats(request).await },
+        )
+        .await
     }
 
     // Helper function to call query operation
-    async fn query(
-        &mut self,
-        request: protos::QueryRequest,
-    ) -> Result<QueryResponse, PineconeError> {
-        let response = self
-            .connection
-            .query(request)
-            .await
-            .map_err(|e| PineconeError::DataPlaneError { status: e })?
-            .into_inner();
+    async fn query(&self, request: protos::QueryRequest) -> Result<QueryResponse, PineconeError> {
+        if let Some(validator) = &self.validator {
+            validator
+                .validate_query(&request)
+                .map_err(|e| self.reject("query", e))?;
+        }
 
-        Ok(response)
+        self.call("query", request, |mut client, request| async move {
+            client.query(request).await
+        })
+        .await
+        .inspect(|response| telemetry::record_usage(response.usage.as_ref()))
     }
 
     /// The update operation updates a vector in a namespace. If a value is included, it will overwrite the previous value.
@@ -228,15 +660,16 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// // Update the vector with id "vector-id" in the namespace "namespace"
     /// let response: Result<UpdateResponse, PineconeError> = index.update("vector-id", vec![1.0, 2.0, 3.0, 4.0], None, None, &"namespace".into()).await;
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, error.kind = tracing::field::Empty)))]
     pub async fn update(
-        &mut self,
+        &self,
         id: &str,
         values: Vec<f32>,
         sparse_values: Option<SparseValues>,
@@ -251,14 +684,16 @@ impl Index {
             namespace: namespace.name.clone(),
         };
 
-        let response = self
-            .connection
-            .update(request)
-            .await
-            .map_err(|e| PineconeError::DataPlaneError { status: e })?
-            .into_inner();
+        if let Some(validator) = &self.validator {
+            validator
+                .validate_update(&request)
+                .map_err(|e| self.reject("update", e))?;
+        }
 
-        Ok(response)
+        self.call("update", request, |mut client, request| async move {
+            client.update(request).await
+        })
+        .await
     }
 
     /// The query operation searches a namespace using a query vector. It retrieves the ids of the most similar items in a namespace, along with their similarity scores.
@@ -283,15 +718,16 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// // Query the vector with id "vector-id" in the namespace "namespace"
     /// let response: Result<QueryResponse, PineconeError> = index.query_by_id("vector-id", 10, &Namespace::default(), None, None, None).await;
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, top_k = top_k, read_units = tracing::field::Empty, error.kind = tracing::field::Empty)))]
     pub async fn query_by_id(
-        &mut self,
+        &self,
         id: &str,
         top_k: u32,
         namespace: &Namespace,
@@ -338,7 +774,7 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// let vector = vec![1.0, 2.0, 3.0, 4.0];
     ///
@@ -348,8 +784,9 @@ impl Index {
     /// # }
     /// ```
     #[allow(clippy::too_many_arguments)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, top_k = top_k, read_units = tracing::field::Empty, error.kind = tracing::field::Empty)))]
     pub async fn query_by_value(
-        &mut self,
+        &self,
         vector: Vec<f32>,
         sparse_vector: Option<SparseValues>,
         top_k: u32,
@@ -392,7 +829,7 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// let ids = ["vector-id"];
     ///
@@ -401,8 +838,9 @@ impl Index {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, batch_size = ids.len(), error.kind = tracing::field::Empty)))]
     pub async fn delete_by_id(
-        &mut self,
+        &self,
         ids: &[&str],
         namespace: &Namespace,
     ) -> Result<(), PineconeError> {
@@ -434,14 +872,15 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// // Delete all vectors from the namespace "namespace"
     /// let response: Result<(), PineconeError> = index.delete_all(&"namespace".into()).await;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete_all(&mut self, namespace: &Namespace) -> Result<(), PineconeError> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, error.kind = tracing::field::Empty)))]
+    pub async fn delete_all(&self, namespace: &Namespace) -> Result<(), PineconeError> {
         let request = protos::DeleteRequest {
             ids: vec![],
             delete_all: true,
@@ -463,28 +902,26 @@ impl Index {
     ///
     /// ### Example
     /// ```no_run
-    /// use std::collections::BTreeMap;
-    /// use pinecone_sdk::models::{Metadata, Value, Kind, Namespace};
+    /// use pinecone_sdk::models::{Filter, Namespace};
     /// # use pinecone_sdk::utils::errors::PineconeError;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// // Construct a metadata filter
-    /// let mut fields = BTreeMap::new();
-    /// let kind = Some(Kind::StringValue("value".to_string()));
-    /// fields.insert("field".to_string(), Value { kind });
+    /// let filter = Filter::eq("field", "value").build()?;
     ///
     /// // Delete vectors from the namespace "namespace" that satisfy the filter
-    /// let response: Result<(), PineconeError> = index.delete_by_filter(Metadata { fields }, &"namespace".into()).await;
+    /// let response: Result<(), PineconeError> = index.delete_by_filter(filter, &"namespace".into()).await;
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, error.kind = tracing::field::Empty)))]
     pub async fn delete_by_filter(
-        &mut self,
+        &self,
         filter: Metadata,
         namespace: &Namespace,
     ) -> Result<(), PineconeError> {
@@ -499,12 +936,11 @@ impl Index {
     }
 
     // Helper function to call delete operation
-    async fn delete(&mut self, request: protos::DeleteRequest) -> Result<(), PineconeError> {
-        let _ = self
-            .connection
-            .delete(request)
-            .await
-            .map_err(|e| PineconeError::DataPlaneError { status: e })?;
+    async fn delete(&self, request: protos::DeleteRequest) -> Result<(), PineconeError> {
+        self.call("delete", request, |mut client, request| async move {
+            client.delete(request).await
+        })
+        .await?;
 
         Ok(())
     }
@@ -528,7 +964,7 @@ impl Index {
     /// # async fn main() -> Result<(), PineconeError>{
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
-    /// let mut index = pinecone.index("index-host").await?;
+    /// let index = pinecone.index("index-host").await?;
     ///
     /// let vectors = &["1", "2"];
     ///
@@ -537,8 +973,9 @@ impl Index {
     /// Ok(())
     /// }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = %self.host, namespace = %namespace.name, batch_size = ids.len(), read_units = tracing::field::Empty, error.kind = tracing::field::Empty)))]
     pub async fn fetch(
-        &mut self,
+        &self,
         ids: &[&str],
         namespace: &Namespace,
     ) -> Result<FetchResponse, PineconeError> {
@@ -548,53 +985,24 @@ impl Index {
             namespace: namespace.name.clone(),
         };
 
-        let response = self
-            .connection
-            .fetch(request)
-            .await
-            .map_err(|e| PineconeError::DataPlaneError { status: e })?
-            .into_inner();
-
-        Ok(response)
-    }
-}
-
-impl PineconeClient {
-    /// Match the scheme in a host string.
-    ///
-    /// ### Arguments
-    /// * `host: &str` - The host string to match.
-    ///
-    /// ### Return
-    /// * `bool` - True if the host string contains a scheme, false otherwise.
-    fn has_scheme(host: &str) -> bool {
-        static RE: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r"^[a-zA-Z]+://").unwrap());
-        RE.is_match(host)
-    }
-
-    /// Match the port in a host string.
-    ///
-    /// ### Arguments
-    /// * `host: &str` - The host string to match.
-    ///
-    /// ### Return
-    /// * `bool` - True if the host string contains a port, false otherwise.
-    fn has_port(host: &str) -> bool {
-        static RE: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r":\d+$").unwrap());
-        RE.is_match(host)
+        self.call("fetch", request, |mut client, request| async move {
+            client.fetch(request).await
+        })
+        .await
+        .inspect(|response| telemetry::record_usage(response.usage.as_ref()))
     }
 
-    /// Target an index for data operations.
+    /// Creates a `QueryBuilder` to query the index.
     ///
-    /// ### Arguments
-    /// * `host: &str` - The host of the index to target. If the host does not contain a scheme, it will default to `https://`. If the host does not contain a port, it will default to `443`.
+    /// The query source is either the ID of a stored vector, set with `id`, or a dense and/or sparse vector,
+    /// set with `vector` and `sparse_vector`. Exactly one of them must be set, along with `top_k`.
     ///
     /// ### Return
-    /// * `Result<Index, PineconeError>`
+    /// * `QueryBuilder`
     ///
     /// ### Example
-    ///
     /// ```no_run
+    /// use pinecone_sdk::models::{Filter, QueryResponse};
     /// # use pinecone_sdk::utils::errors::PineconeError;
     ///
     /// # #[tokio::main]
@@ -602,70 +1010,511 @@ impl PineconeClient {
     /// let pinecone = pinecone_sdk::pinecone::default_client()?;
     ///
     /// let index = pinecone.index("index-host").await?;
+    ///
+    /// // Query the 10 vectors closest to the vector in the namespace "namespace"
+    /// let response: QueryResponse = index
+    ///     .query_builder()
+    ///     .vector(vec![1.0, 2.0, 3.0, 4.0])
+    ///     .top_k(10)
+    ///     .namespace("namespace")
+    ///     .filter(Filter::eq("genre", "comedy").build()?)
+    ///     .include_metadata(true)
+    ///     .execute()
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn index(&self, host: &str) -> Result<Index, PineconeError> {
-        let endpoint = host.to_string();
+    pub fn query_builder(&self) -> QueryBuilder<'_> {
+        QueryBuilder {
+            index: self,
+            top_k: None,
+            namespace: Namespace::default(),
+            filter: None,
+            include_values: false,
+            include_metadata: false,
+            vector: None,
+            sparse_vector: None,
+            id: None,
+            deadline: None,
+        }
+    }
+}
 
-        let endpoint = if PineconeClient::has_scheme(&endpoint) {
-            endpoint
-        } else {
-            format!("https://{}", endpoint)
-        };
+/// A builder for query requests, created with `Index::query_builder`.
+#[derive(Debug, Clone)]
+pub struct QueryBuilder<'a> {
+    index: &'a Index,
+    top_k: Option<u32>,
+    namespace: Namespace,
+    filter: Option<Metadata>,
+    include_values: bool,
+    include_metadata: bool,
+    vector: Option<Vec<f32>>,
+    sparse_vector: Option<SparseValues>,
+    id: Option<String>,
+    deadline: Option<Duration>,
+}
 
-        let endpoint = if PineconeClient::has_port(&endpoint) {
-            endpoint
-        } else {
-            format!("{}:443", endpoint)
-        };
+impl<'a> QueryBuilder<'a> {
+    /// Sets the number of results to return. Required.
+    pub fn top_k(mut self, top_k: u32) -> Self {
+        self.top_k = Some(top_k);
+        self
+    }
 
-        let index = Index {
-            host: endpoint.clone(),
-            connection: self.new_index_connection(endpoint).await?,
-        };
+    /// Sets the namespace to query. Default is "".
+    pub fn namespace(mut self, namespace: impl Into<Namespace>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
 
-        Ok(index)
+    /// Sets the filter to apply to limit the search by vector metadata.
+    pub fn filter(mut self, filter: Metadata) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets whether to include the values of the vectors in the response. Default is false.
+    pub fn include_values(mut self, include_values: bool) -> Self {
+        self.include_values = include_values;
+        self
+    }
+
+    /// Sets whether to include the metadata of the vectors in the response. Default is false.
+    pub fn include_metadata(mut self, include_metadata: bool) -> Self {
+        self.include_metadata = include_metadata;
+        self
+    }
+
+    /// Sets the dense vector to query with.
+    pub fn vector(mut self, vector: Vec<f32>) -> Self {
+        self.vector = Some(vector);
+        self
+    }
+
+    /// Sets the sparse vector to query with.
+    pub fn sparse_vector(mut self, sparse_vector: SparseValues) -> Self {
+        self.sparse_vector = Some(sparse_vector);
+        self
+    }
+
+    /// Sets the ID of the stored vector to query with.
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Sets the deadline of the query, overriding the deadline of the index. See `Index::with_deadline`.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sends the query.
+    ///
+    /// ### Return
+    /// * `Result<QueryResponse, PineconeError>` - `PineconeError::InvalidConfigurationError` if `top_k` is not set,
+    ///   or if not exactly one of an ID and a dense and/or sparse vector is set.
+    #[cfg_attr(feature = "tracing", tracing::instrument(name = "query", skip_all, fields(index.host = %self.index.host, namespace = %self.namespace.name, top_k = self.top_k, read_units = tracing::field::Empty, error.kind = tracing::field::Empty)))]
+    pub async fn execute(self) -> Result<QueryResponse, PineconeError> {
+        let index = self.index;
+        let deadline = self.deadline;
+        let request = self.build_request().map_err(|e| index.reject("query", e))?;
+
+        match deadline {
+            Some(deadline) => index.with_deadline(deadline).query(request).await,
+            None => index.query(request).await,
+        }
+    }
+
+    // Helper function to validate the builder and build the query request
+    fn build_request(self) -> Result<protos::QueryRequest, PineconeError> {
+        let top_k = self
+            .top_k
+            .ok_or_else(|| PineconeError::InvalidConfigurationError {
+                message: "top_k must be set".to_string(),
+            })?;
+
+        let by_value = self.vector.is_some() || self.sparse_vector.is_some();
+        let id = match (self.id, by_value) {
+            (Some(id), false) => id,
+            (None, true) => "".to_string(),
+            (Some(_), true) => {
+                return Err(PineconeError::InvalidConfigurationError {
+                    message: "Only one of id and vector or sparse_vector can be set".to_string(),
+                })
+            }
+            (None, false) => {
+                return Err(PineconeError::InvalidConfigurationError {
+                    message: "One of id, vector or sparse_vector must be set".to_string(),
+                })
+            }
+        };
+
+        #[allow(deprecated)]
+        let request = protos::QueryRequest {
+            id,
+            top_k,
+            namespace: self.namespace.name,
+            filter: self.filter,
+            include_values: self.include_values,
+            include_metadata: self.include_metadata,
+            queries: vec![],
+            vector: self.vector.unwrap_or_default(),
+            sparse_vector: self.sparse_vector,
+        };
+
+        Ok(request)
+    }
+}
+
+// Returns the token of the page following `page`, or `None` if `page` is the last page
+fn next_pagination_token(page: &ListResponse) -> Option<String> {
+    page.pagination
+        .as_ref()
+        .map(|pagination| pagination.next.clone())
+        .filter(|next| !next.is_empty())
+}
+
+// Splits vectors into consecutive batches of at most `max_count` vectors, whose upsert requests are at most `max_bytes` bytes.
+// A vector that does not fit in `max_bytes` on its own is put in a batch by itself.
+fn split_upsert_batches<'a>(
+    vectors: &'a [Vector],
+    namespace: &Namespace,
+    max_count: usize,
+    max_bytes: usize,
+) -> Vec<&'a [Vector]> {
+    let base_len = protos::UpsertRequest {
+        vectors: vec![],
+        namespace: namespace.name.clone(),
+    }
+    .encoded_len();
+
+    let mut batches = vec![];
+    let mut start = 0;
+    let mut batch_len = base_len;
+
+    for (i, vector) in vectors.iter().enumerate() {
+        let vector_len = prost::encoding::message::encoded_len(1, vector);
+        if i > start && (i - start >= max_count || batch_len + vector_len > max_bytes) {
+            batches.push(&vectors[start..i]);
+            start = i;
+            batch_len = base_len;
+        }
+        batch_len += vector_len;
+    }
+
+    if start < vectors.len() {
+        batches.push(&vectors[start..]);
+    }
+
+    batches
+}
+
+impl PineconeClient {
+    /// Match the scheme in a host string.
+    ///
+    /// ### Arguments
+    /// * `host: &str` - The host string to match.
+    ///
+    /// ### Return
+    /// * `bool` - True if the host string contains a scheme, false otherwise.
+    fn has_scheme(host: &str) -> bool {
+        static RE: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r"^[a-zA-Z]+://").unwrap());
+        RE.is_match(host)
+    }
+
+    /// Match the port in a host string.
+    ///
+    /// ### Arguments
+    /// * `host: &str` - The host string to match.
+    ///
+    /// ### Return
+    /// * `bool` - True if the host string contains a port, false otherwise.
+    fn has_port(host: &str) -> bool {
+        static RE: Lazy<regex::Regex> = Lazy::new(|| regex::Regex::new(r":\d+$").unwrap());
+        RE.is_match(host)
+    }
+
+    /// Target an index for data operations.
+    ///
+    /// ### Arguments
+    /// * `host: &str` - The host of the index to target. If the host does not contain a scheme, it will default to `https://`, or to `http://`
+    ///   if the client is configured with `plaintext`. If the host does not contain a port, it will default to `443` for `https://` and `80` for `http://`.
+    ///   `https://` hosts are connected to with TLS, and `http://` hosts with plaintext.
+    ///
+    /// ### Return
+    /// * `Result<Index, PineconeError>`
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let index = pinecone.index("index-host").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.host = host, error.kind = tracing::field::Empty)))]
+    pub async fn index(&self, host: &str) -> Result<Index, PineconeError> {
+        let endpoint = host.to_string();
+
+        let endpoint = if PineconeClient::has_scheme(&endpoint) {
+            endpoint
+        } else if self.connection_settings.plaintext {
+            format!("http://{}", endpoint)
+        } else {
+            format!("https://{}", endpoint)
+        };
+
+        let endpoint = if PineconeClient::has_port(&endpoint) {
+            endpoint
+        } else if endpoint.starts_with("http://") {
+            format!("{}:80", endpoint)
+        } else {
+            format!("{}:443", endpoint)
+        };
+
+        let index = Index {
+            host: endpoint.clone(),
+            connection: self.new_index_connection(endpoint).await?,
+            retry_policy: self.retry_policy.clone(),
+            name: None,
+            host_cache: self.index_host_cache.clone(),
+            validator: None,
+            deadline: self.connection_settings.default_deadline,
+            metrics_observer: self.metrics_observer.clone(),
+        };
+
+        Ok(index)
+    }
+
+    /// Target an index for data operations by its name.
+    ///
+    /// The host of the index is resolved with `describe_index` and cached by the client, so that subsequent calls
+    /// for the same index do not go through the control plane. The cached host is invalidated when a data plane
+    /// operation reports that the index was not found or is unavailable, for example because it was recreated.
+    ///
+    /// ### Arguments
+    /// * `name: &str` - The name of the index to target.
+    ///
+    /// ### Return
+    /// * `Result<Index, PineconeError>`
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let index = pinecone.index_by_name("index-name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "control")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.name = name, error.kind = tracing::field::Empty)))]
+    pub async fn index_by_name(&self, name: &str) -> Result<Index, PineconeError> {
+        let host = match self.index_host_cache.get(name) {
+            Some(host) => host,
+            None => {
+                let host = self.describe_index(name).await?.host;
+                self.index_host_cache.insert(name, &host);
+                host
+            }
+        };
+
+        let mut index = self
+            .index(&host)
+            .await
+            .inspect_err(|_| self.index_host_cache.invalidate(name))?;
+        index.name = Some(name.to_string());
+
+        Ok(index)
+    }
+
+    /// Target an index for data operations by its name, with client-side validation of vectors and queries against
+    /// its dimension and metric.
+    ///
+    /// The index is described with `describe_index`, whose host is cached as by `index_by_name`. See
+    /// `Index::with_validation` for the checks that are made.
+    ///
+    /// ### Arguments
+    /// * `name: &str` - The name of the index to target.
+    ///
+    /// ### Return
+    /// * `Result<Index, PineconeError>`
+    ///
+    /// ### Example
+    ///
+    /// ```no_run
+    /// # use pinecone_sdk::utils::errors::PineconeError;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), PineconeError>{
+    /// let pinecone = pinecone_sdk::pinecone::default_client()?;
+    ///
+    /// let index = pinecone.index_with_validation("index-name").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "control")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(index.name = name, error.kind = tracing::field::Empty)))]
+    pub async fn index_with_validation(&self, name: &str) -> Result<Index, PineconeError> {
+        let description = self.describe_index(name).await?;
+        self.index_host_cache.insert(name, &description.host);
+
+        let mut index = self
+            .index(&description.host)
+            .await
+            .inspect_err(|_| self.index_host_cache.invalidate(name))?;
+        index.name = Some(name.to_string());
+
+        Ok(index.with_validation(&description))
     }
 
     // Helper function to create a new index connection
     async fn new_index_connection(
         &self,
         host: String,
-    ) -> Result<VectorServiceClient<InterceptedService<Channel, ApiKeyInterceptor>>, PineconeError>
-    {
-        // tonic 0.12 no longer loads trust roots implicitly: the previous
-        // `ClientTlsConfig::default()` produced an empty root store, so every TLS
-        // handshake would fail with "invalid peer certificate: UnknownIssuer".
-        // Explicitly load the platform's native root certificates (matches the
-        // `tls-roots` feature enabled in Cargo.toml).
-        let tls_config = tonic::transport::ClientTlsConfig::default().with_native_roots();
+    ) -> Result<DataPlaneConnection, PineconeError> {
+        let settings = &self.connection_settings;
+
+        // the REST API is sent through the HTTP client of the control plane, which shares its TLS, proxy and timeouts
+        if settings.data_plane_transport == DataPlaneTransport::Rest {
+            #[cfg(not(feature = "serde"))]
+            return Err(PineconeError::InvalidConfigurationError {
+                message: "The REST data plane transport requires the `serde` feature".to_string(),
+            });
+            #[cfg(feature = "serde")]
+            return Ok(DataPlaneConnection::Rest(RestClient::new(
+                self.openapi_config.client.clone(),
+                host,
+                self.api_key.clone(),
+                self.user_agent.clone(),
+            )));
+        }
 
         // connect to server
-        let endpoint = Channel::from_shared(host)
+        let use_tls = host.starts_with("https://");
+        let mut endpoint = Channel::from_shared(host)
             .map_err(|e| PineconeError::ConnectionError { source: e.into() })?
-            .tls_config(tls_config)
-            .map_err(|e| PineconeError::ConnectionError { source: e.into() })?;
+            .tcp_keepalive(settings.tcp_keepalive);
+
+        // the scheme decides whether TLS is used: `http://` hosts, such as local emulators, are plaintext
+        #[cfg(not(feature = "rustls"))]
+        if use_tls {
+            return Err(PineconeError::InvalidConfigurationError {
+                message: "TLS connections to the data plane require the `rustls` feature"
+                    .to_string(),
+            });
+        }
+        #[cfg(feature = "rustls")]
+        if use_tls {
+            // tonic 0.12 no longer loads trust roots implicitly: the previous
+            // `ClientTlsConfig::default()` produced an empty root store, so every TLS
+            // handshake would fail with "invalid peer certificate: UnknownIssuer".
+            // Explicitly load the configured built-in root certificates (matches the
+            // `tls-roots` and `tls-webpki-roots` features enabled in Cargo.toml).
+            let tls = &settings.tls;
+            let mut tls_config = match tls.roots {
+                TlsRoots::Native => ClientTlsConfig::default().with_native_roots(),
+                TlsRoots::Webpki => ClientTlsConfig::default().with_webpki_roots(),
+            };
+            for pem in &tls.extra_root_certificates_pem {
+                tls_config = tls_config.ca_certificate(Certificate::from_pem(pem));
+            }
+            if let Some(identity) = &tls.client_identity {
+                tls_config = tls_config.identity(Identity::from_pem(
+                    &identity.certificate_pem,
+                    &identity.private_key_pem,
+                ));
+            }
+            endpoint = endpoint
+                .tls_config(tls_config)
+                .map_err(|e| PineconeError::ConnectionError { source: e.into() })?;
+        }
+        if let Some(timeout) = settings.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(timeout) = settings.request_timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(interval) = settings.http2_keepalive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = settings.http2_keepalive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+        if let Some(limit) = settings.concurrency_limit {
+            endpoint = endpoint.concurrency_limit(limit);
+        }
 
-        let channel = endpoint
-            .connect()
-            .await
-            .map_err(|e| PineconeError::ConnectionError { source: e.into() })?;
+        let channel = match &settings.proxy {
+            Some(proxy) => {
+                let proxy = proxy.clone();
+                let tcp_keepalive = settings.tcp_keepalive;
+                let connector = tower::service_fn(move |uri| {
+                    let proxy = proxy.clone();
+                    async move { proxy.tunnel(uri, tcp_keepalive).await }
+                });
+                endpoint.connect_with_connector(connector).await
+            }
+            None => endpoint.connect().await,
+        }
+        .map_err(|e| PineconeError::ConnectionError { source: e.into() })?;
 
         // add api key in metadata through interceptor
         let token: TonicMetadataVal<_> = self.api_key.parse().unwrap();
         let add_api_key_interceptor = ApiKeyInterceptor { api_token: token };
-        let inner = VectorServiceClient::with_interceptor(channel, add_api_key_interceptor);
+        let mut inner = VectorServiceClient::with_interceptor(channel, add_api_key_interceptor);
+        if let Some(limit) = settings.max_decoding_message_size {
+            inner = inner.max_decoding_message_size(limit);
+        }
+        if let Some(limit) = settings.max_encoding_message_size {
+            inner = inner.max_encoding_message_size(limit);
+        }
 
-        Ok(inner)
+        Ok(DataPlaneConnection::Grpc(inner))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::pinecone::default_client;
+    use super::*;
+    use crate::pinecone::{default_client, PineconeClientConfig};
+    use crate::utils::metrics::tests::RecordingObserver;
+    use crate::utils::metrics::OperationOutcome;
     use httpmock::prelude::*;
 
+    #[cfg(feature = "control")]
+    fn describe_index_body(host: &str) -> String {
+        format!(
+            r#"{{
+                "dimension": 4,
+                "host": "{host}",
+                "metric": "cosine",
+                "name": "index-name",
+                "spec": {{
+                    "serverless": {{
+                        "cloud": "aws",
+                        "region": "us-east-1"
+                    }}
+                }},
+                "status": {{
+                    "ready": true,
+                    "state": "Ready"
+                }}
+            }}"#
+        )
+    }
+
     #[tokio::test]
     async fn test_index_full_endpoint() {
         let server = MockServer::start();
@@ -738,4 +1587,763 @@ mod tests {
             .await
             .expect_err("Expected connection error");
     }
+
+    #[tokio::test]
+    async fn test_index_plaintext_no_scheme() {
+        let server = MockServer::start();
+
+        // server url contains no scheme, and the client defaults to plaintext
+        let _mock = server.mock(|_when, then| {
+            then.status(200);
+        });
+
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .plaintext(true)
+            .client()
+            .expect("Failed to create Pinecone instance");
+
+        let addr = server.address().to_string();
+
+        let index = pinecone
+            .index(addr.as_str())
+            .await
+            .expect("Expected plaintext connection to succeed");
+
+        assert_eq!(index.host, format!("http://{}", addr));
+    }
+
+    #[cfg(feature = "control")]
+    #[tokio::test]
+    async fn test_index_by_name_cached() {
+        let data_plane = MockServer::start();
+        let _data_plane_mock = data_plane.mock(|_when, then| {
+            then.status(200);
+        });
+
+        let control_plane = MockServer::start();
+        let describe_mock = control_plane.mock(|when, then| {
+            when.method(GET).path("/indexes/index-name");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(describe_index_body(&data_plane.base_url()));
+        });
+
+        let config = PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            control_plane_host: Some(control_plane.base_url()),
+            ..Default::default()
+        };
+        let pinecone = config.client().expect("Failed to create Pinecone instance");
+
+        let index = pinecone
+            .index_by_name("index-name")
+            .await
+            .expect("Failed to target index by name");
+        assert_eq!(index.host, data_plane.base_url());
+        assert_eq!(index.name, Some("index-name".to_string()));
+
+        let _index = pinecone
+            .index_by_name("index-name")
+            .await
+            .expect("Failed to target index by name");
+
+        describe_mock.assert_hits(1);
+    }
+
+    #[cfg(feature = "control")]
+    #[tokio::test]
+    async fn test_index_by_name_connection_error_invalidates_cache() {
+        let control_plane = MockServer::start();
+        let describe_mock = control_plane.mock(|when, then| {
+            when.method(GET).path("/indexes/index-name");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(describe_index_body("http://localhost:1"));
+        });
+
+        let config = PineconeClientConfig {
+            api_key: Some("api_key".to_string()),
+            control_plane_host: Some(control_plane.base_url()),
+            ..Default::default()
+        };
+        let pinecone = config.client().expect("Failed to create Pinecone instance");
+
+        for _ in 0..2 {
+            let _ = pinecone
+                .index_by_name("index-name")
+                .await
+                .expect_err("Expected connection error");
+        }
+
+        describe_mock.assert_hits(2);
+    }
+
+    #[cfg(all(feature = "control", feature = "testing"))]
+    #[tokio::test]
+    async fn test_index_with_validation() {
+        use crate::models::{Cloud, DeletionProtection, Metric, WaitPolicy};
+        use crate::testing::{FakeControlPlane, FakeDataPlane};
+
+        let data_plane = FakeDataPlane::new(2, Metric::Cosine)
+            .start()
+            .await
+            .expect("Failed to start fake data plane");
+        let control_plane = FakeControlPlane::new()
+            .data_plane_host(data_plane.host())
+            .start()
+            .await
+            .expect("Failed to start fake control plane");
+
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .control_plane_host(control_plane.host())
+            .client()
+            .expect("Failed to create Pinecone instance");
+        pinecone
+            .create_serverless_index(
+                "index-name",
+                2,
+                Metric::Cosine,
+                Cloud::Aws,
+                "us-east-1",
+                DeletionProtection::Disabled,
+                WaitPolicy::NoWait,
+            )
+            .await
+            .expect("Failed to create index");
+
+        let index = pinecone
+            .index_with_validation("index-name")
+            .await
+            .expect("Failed to target index");
+        index
+            .upsert(&[vector("id1", 2)], &Namespace::default())
+            .await
+            .expect("Failed to upsert");
+
+        // the dimension and metric are learned from the description of the index
+        let error = index
+            .upsert(&[vector("id2", 3)], &Namespace::default())
+            .await
+            .expect_err("Expected upsert to be rejected");
+        assert!(matches!(error, PineconeError::ValidationError { .. }));
+
+        let mut sparse = vector("id3", 2);
+        sparse.sparse_values = Some(SparseValues {
+            indices: vec![0],
+            values: vec![0.5],
+        });
+        let error = index
+            .upsert(&[sparse], &Namespace::default())
+            .await
+            .expect_err("Expected upsert to be rejected");
+        assert!(matches!(error, PineconeError::ValidationError { .. }));
+
+        let error = pinecone
+            .index_with_validation("other-index")
+            .await
+            .expect_err("Expected index to not be found");
+        assert!(matches!(error, PineconeError::IndexNotFoundError { .. }));
+    }
+
+    #[cfg(all(feature = "control", feature = "testing"))]
+    #[tokio::test]
+    async fn test_index_by_name_data_plane_error_invalidates_cache() {
+        use crate::models::{Cloud, DeletionProtection, Metric, WaitPolicy};
+        use crate::testing::{FakeControlPlane, FakeDataPlane};
+
+        let data_plane = FakeDataPlane::new(2, Metric::Cosine)
+            .start()
+            .await
+            .expect("Failed to start fake data plane");
+        let control_plane = FakeControlPlane::new()
+            .data_plane_host(data_plane.host())
+            .start()
+            .await
+            .expect("Failed to start fake control plane");
+
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .control_plane_host(control_plane.host())
+            .client()
+            .expect("Failed to create Pinecone instance");
+        pinecone
+            .create_serverless_index(
+                "index-name",
+                2,
+                Metric::Cosine,
+                Cloud::Aws,
+                "us-east-1",
+                DeletionProtection::Disabled,
+                WaitPolicy::NoWait,
+            )
+            .await
+            .expect("Failed to create index");
+
+        let index = pinecone
+            .index_by_name("index-name")
+            .await
+            .expect("Failed to target index");
+        index
+            .describe_index_stats(None)
+            .await
+            .expect("Failed to describe index stats");
+
+        // once the index is deleted by another client, it can only be targeted through the cached host
+        let other = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .control_plane_host(control_plane.host())
+            .client()
+            .expect("Failed to create Pinecone instance");
+        other
+            .delete_index("index-name")
+            .await
+            .expect("Failed to delete index");
+        let _ = pinecone
+            .index_by_name("index-name")
+            .await
+            .expect("Expected the cached host to be used");
+
+        // wait for the server to close the connection once it is shut down
+        drop(data_plane);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let error = index
+            .describe_index_stats(None)
+            .await
+            .expect_err("Expected the data plane to be unavailable");
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneUnavailableError { .. }
+        ));
+
+        // the host is described again by the control plane, which no longer has the index
+        let error = pinecone
+            .index_by_name("index-name")
+            .await
+            .expect_err("Expected the index not to be found");
+        assert!(matches!(error, PineconeError::IndexNotFoundError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_index_is_send_sync_clone() {
+        fn assert_send_sync_clone<T: Send + Sync + Clone>() {}
+        assert_send_sync_clone::<super::Index>();
+    }
+
+    fn vector(id: &str, dimension: usize) -> Vector {
+        Vector {
+            id: id.to_string(),
+            values: vec![0.5; dimension],
+            sparse_values: None,
+            metadata: None,
+        }
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_upsert_batched_partial_failure() {
+        use crate::models::Metric;
+        use crate::testing::FakeDataPlane;
+
+        let server = FakeDataPlane::new(2, Metric::Cosine)
+            .start()
+            .await
+            .expect("Failed to start fake data plane");
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .client()
+            .expect("Failed to create Pinecone instance");
+        let index = pinecone
+            .index(&server.host())
+            .await
+            .expect("Failed to target index");
+
+        // the batch of vectors 4 to 7 is rejected for the dimension of vector 5
+        let vectors: Vec<Vector> = (0..10)
+            .map(|i| vector(&i.to_string(), if i == 5 { 3 } else { 2 }))
+            .collect();
+        let response = index
+            .upsert_batched(&vectors, &Namespace::default(), Some(4), Some(2))
+            .await
+            .expect("Failed to upsert batches");
+
+        assert!(!response.is_success());
+        assert_eq!(response.upserted_count, 6);
+        assert_eq!(response.failed_batches.len(), 1);
+        assert_eq!(response.failed_batches[0].ids, ["4", "5", "6", "7"]);
+        assert!(matches!(
+            response.failed_batches[0].error,
+            PineconeError::DataPlaneInvalidArgumentError { .. }
+        ));
+        assert_eq!(response.failed_ids(), ["4", "5", "6", "7"]);
+
+        let stats = index
+            .describe_index_stats(None)
+            .await
+            .expect("Failed to describe index stats");
+        assert_eq!(stats.total_vector_count, 6);
+    }
+
+    // Starts a fake data plane, and upserts 250 vectors with IDs "vector-000" to "vector-249" into "namespace"
+    #[cfg(feature = "testing")]
+    async fn start_with_vectors() -> (crate::testing::FakeServer, Index) {
+        use crate::models::Metric;
+        use crate::testing::FakeDataPlane;
+
+        let server = FakeDataPlane::new(2, Metric::Cosine)
+            .start()
+            .await
+            .expect("Failed to start fake data plane");
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .client()
+            .expect("Failed to create Pinecone instance");
+        let index = pinecone
+            .index(&server.host())
+            .await
+            .expect("Failed to target index");
+
+        let vectors: Vec<Vector> = (0..250)
+            .map(|i| vector(&format!("vector-{i:03}"), 2))
+            .collect();
+        index
+            .upsert(&vectors, &"namespace".into())
+            .await
+            .expect("Failed to upsert");
+
+        (server, index)
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_list_pages_follows_pagination() {
+        let (_server, index) = start_with_vectors().await;
+
+        let pages: Vec<ListResponse> = index
+            .list_pages(&"namespace".into(), None)
+            .try_collect()
+            .await
+            .expect("Failed to list pages");
+
+        // pages hold 100 IDs by default, and the last page has no pagination token
+        let sizes: Vec<usize> = pages.iter().map(|page| page.vectors.len()).collect();
+        assert_eq!(sizes, vec![100, 100, 50]);
+        assert!(pages[0].pagination.is_some());
+        assert!(pages[1].pagination.is_some());
+        assert!(pages[2].pagination.is_none());
+
+        let ids: Vec<String> = pages
+            .into_iter()
+            .flat_map(|page| page.vectors.into_iter().map(|item| item.id))
+            .collect();
+        let expected: Vec<String> = (0..250).map(|i| format!("vector-{i:03}")).collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_list_all() {
+        let (_server, mut index) = start_with_vectors().await;
+        let observer = Arc::new(RecordingObserver::default());
+        index.metrics_observer = Some(observer.clone());
+        let list_requests = || {
+            let events = observer.events.lock().unwrap();
+            events.iter().filter(|event| event.0 == "list").count()
+        };
+
+        let ids: Vec<String> = index
+            .list_all(&"namespace".into(), None, None)
+            .try_collect()
+            .await
+            .expect("Failed to list IDs");
+        let expected: Vec<String> = (0..250).map(|i| format!("vector-{i:03}")).collect();
+        assert_eq!(ids, expected);
+        assert_eq!(list_requests(), 3);
+
+        // the listing stops in the second page, without requesting the third one
+        let ids: Vec<String> = index
+            .list_all(&"namespace".into(), None, Some(150))
+            .try_collect()
+            .await
+            .expect("Failed to list IDs");
+        assert_eq!(ids, expected[..150]);
+        assert_eq!(list_requests(), 5);
+
+        let ids: Vec<String> = index
+            .list_all(&"namespace".into(), Some("vector-1"), None)
+            .try_collect()
+            .await
+            .expect("Failed to list IDs");
+        assert_eq!(ids, expected[100..200]);
+    }
+
+    #[tokio::test]
+    async fn test_split_upsert_batches_by_count() {
+        let vectors: Vec<Vector> = (0..10).map(|i| vector(&i.to_string(), 4)).collect();
+
+        let batches = split_upsert_batches(&vectors, &Namespace::default(), 4, usize::MAX);
+
+        let sizes: Vec<usize> = batches.iter().map(|b| b.len()).collect();
+        assert_eq!(sizes, vec![4, 4, 2]);
+        assert_eq!(batches[2][1].id, "9");
+    }
+
+    #[tokio::test]
+    async fn test_split_upsert_batches_by_size() {
+        let vectors: Vec<Vector> = (0..10).map(|i| vector(&i.to_string(), 100)).collect();
+        let namespace: Namespace = "namespace".into();
+
+        let vector_len = prost::encoding::message::encoded_len(1, &vectors[0]);
+        let max_bytes = 3 * vector_len + 20;
+
+        let batches = split_upsert_batches(&vectors, &namespace, 100, max_bytes);
+
+        let sizes: Vec<usize> = batches.iter().map(|b| b.len()).collect();
+        assert_eq!(sizes, vec![3, 3, 3, 1]);
+        for batch in batches {
+            let request = protos::UpsertRequest {
+                vectors: batch.to_vec(),
+                namespace: namespace.name.clone(),
+            };
+            assert!(request.encoded_len() <= max_bytes);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_split_upsert_batches_oversized_vector() {
+        let vectors = vec![vector("1", 4), vector("2", 1000), vector("3", 4)];
+
+        let batches = split_upsert_batches(&vectors, &Namespace::default(), 100, 1000);
+
+        let sizes: Vec<usize> = batches.iter().map(|b| b.len()).collect();
+        assert_eq!(sizes, vec![1, 1, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_split_upsert_batches_empty() {
+        let batches = split_upsert_batches(&[], &Namespace::default(), 100, 1000);
+        assert!(batches.is_empty());
+    }
+
+    // Creates an index whose connection is only established on first use
+    fn lazy_index() -> Index {
+        lazy_index_with_host("http://localhost:5080")
+    }
+
+    fn lazy_index_with_host(host: &str) -> Index {
+        let channel = Channel::from_shared(host.to_string())
+            .unwrap()
+            .connect_lazy();
+        let interceptor = ApiKeyInterceptor {
+            api_token: "api-key".parse().unwrap(),
+        };
+
+        Index {
+            host: host.to_string(),
+            connection: DataPlaneConnection::Grpc(VectorServiceClient::with_interceptor(
+                channel,
+                interceptor,
+            )),
+            retry_policy: RetryPolicy::disabled(),
+            name: None,
+            host_cache: IndexHostCache::new(Duration::from_secs(60)),
+            validator: None,
+            deadline: None,
+            metrics_observer: None,
+        }
+    }
+
+    // Starts a server that accepts connections but never responds
+    async fn unresponsive_server() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_deadline_exceeded() {
+        let index = lazy_index_with_host(&unresponsive_server().await)
+            .with_deadline(Duration::from_millis(100));
+
+        let error = index
+            .fetch(&["1"], &Namespace::default())
+            .await
+            .expect_err("Expected fetch to exceed its deadline");
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+        assert!(error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_deadline_max() {
+        let index = lazy_index().with_deadline(Duration::MAX);
+
+        let error = index
+            .fetch(&["1"], &Namespace::default())
+            .await
+            .expect_err("Expected fetch to fail to connect");
+        assert!(!matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deadline_metrics_observer() {
+        let observer = Arc::new(RecordingObserver::default());
+        let mut index = lazy_index_with_host(&unresponsive_server().await)
+            .with_deadline(Duration::from_millis(100));
+        index.metrics_observer = Some(observer.clone());
+
+        let _ = index.fetch(&["1"], &Namespace::default()).await;
+
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec![("fetch", OperationOutcome::TransientError, None, None)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deadline_includes_retries() {
+        let mut index = lazy_index_with_host(&unresponsive_server().await)
+            .with_deadline(Duration::from_millis(200));
+        index.retry_policy = RetryPolicy::default();
+
+        let start = std::time::Instant::now();
+        let error = index
+            .describe_index_stats(None)
+            .await
+            .expect_err("Expected describe_index_stats to exceed its deadline");
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_query_builder_deadline() {
+        let index = lazy_index_with_host(&unresponsive_server().await);
+
+        let error = index
+            .query_builder()
+            .top_k(10)
+            .id("1")
+            .deadline(Duration::from_millis(100))
+            .execute()
+            .await
+            .expect_err("Expected query to exceed its deadline");
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[tokio::test]
+    async fn test_rest_transport_requires_serde() {
+        let error = PineconeClientConfig::builder()
+            .api_key("api_key")
+            .data_plane_transport(DataPlaneTransport::Rest)
+            .client()
+            .expect("Failed to create Pinecone instance")
+            .index("http://localhost:5080")
+            .await
+            .expect_err("Expected the REST transport to be unavailable");
+        assert!(matches!(
+            error,
+            PineconeError::InvalidConfigurationError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_default_deadline() {
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api-key")
+            .default_deadline(Duration::from_secs(5))
+            .client()
+            .unwrap();
+
+        let index = pinecone.index(&unresponsive_server().await).await.unwrap();
+        assert_eq!(index.deadline, Some(Duration::from_secs(5)));
+
+        let index = index.with_deadline(Duration::from_secs(1));
+        assert_eq!(index.deadline, Some(Duration::from_secs(1)));
+    }
+
+    #[tokio::test]
+    async fn test_query_builder_by_id() {
+        let index = lazy_index();
+
+        let request = index
+            .query_builder()
+            .id("vector-id")
+            .top_k(5)
+            .namespace("namespace")
+            .include_values(true)
+            .build_request()
+            .expect("Expected query to be valid");
+
+        assert_eq!(request.id, "vector-id");
+        assert_eq!(request.top_k, 5);
+        assert_eq!(request.namespace, "namespace");
+        assert!(request.include_values);
+        assert!(!request.include_metadata);
+        assert!(request.vector.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_query_builder_by_value() {
+        let index = lazy_index();
+        let sparse_vector = SparseValues {
+            indices: vec![1, 3],
+            values: vec![0.5, 0.25],
+        };
+
+        let request = index
+            .query_builder()
+            .vector(vec![1.0, 2.0])
+            .sparse_vector(sparse_vector.clone())
+            .top_k(10)
+            .include_metadata(true)
+            .build_request()
+            .expect("Expected query to be valid");
+
+        assert_eq!(request.id, "");
+        assert_eq!(request.vector, vec![1.0, 2.0]);
+        assert_eq!(request.sparse_vector, Some(sparse_vector));
+        assert_eq!(request.namespace, "");
+        assert!(request.include_metadata);
+    }
+
+    #[tokio::test]
+    async fn test_query_builder_invalid() {
+        let index = lazy_index();
+
+        let invalid = [
+            index.query_builder().top_k(10),
+            index.query_builder().id("vector-id"),
+            index
+                .query_builder()
+                .id("vector-id")
+                .vector(vec![1.0])
+                .top_k(10),
+        ];
+
+        for builder in invalid {
+            let error = builder
+                .build_request()
+                .expect_err("Expected query to be invalid");
+            assert!(matches!(
+                error,
+                PineconeError::InvalidConfigurationError { .. }
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_validation_rejects_before_sending() {
+        let index = lazy_index().with_validation(&IndexModel {
+            dimension: 4,
+            ..Default::default()
+        });
+
+        let res = index
+            .upsert_batched(
+                &[vector("id1", 4), vector("id2", 3)],
+                &"".into(),
+                None,
+                None,
+            )
+            .await;
+        match res {
+            Err(PineconeError::ValidationError { id, field, .. }) => {
+                assert_eq!(id.as_deref(), Some("id2"));
+                assert_eq!(field, "values");
+            }
+            res => panic!("Expected validation error, got {:?}", res),
+        }
+
+        let res = index
+            .query_builder()
+            .vector(vec![1.0])
+            .top_k(10)
+            .execute()
+            .await;
+        assert!(matches!(res, Err(PineconeError::ValidationError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_operations_metrics_observer() {
+        let observer = Arc::new(RecordingObserver::default());
+        let mut index = lazy_index().with_validation(&IndexModel {
+            dimension: 4,
+            ..Default::default()
+        });
+        index.metrics_observer = Some(observer.clone());
+
+        let _ = index
+            .upsert(&[vector("id1", 3)], &Namespace::default())
+            .await
+            .expect_err("Expected upsert to be rejected");
+        let _ = index
+            .upsert_batched(&[vector("id1", 4)], &Namespace::default(), Some(0), None)
+            .await
+            .expect_err("Expected upsert_batched to be rejected");
+        let _ = index
+            .update("id1", vec![1.0], None, None, &Namespace::default())
+            .await
+            .expect_err("Expected update to be rejected");
+        let _ = index
+            .query_builder()
+            .top_k(10)
+            .execute()
+            .await
+            .expect_err("Expected query to be rejected");
+
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec![
+                ("upsert", OperationOutcome::ClientError, None, None),
+                ("upsert", OperationOutcome::ClientError, None, None),
+                ("update", OperationOutcome::ClientError, None, None),
+                ("query", OperationOutcome::ClientError, None, None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_pagination_token() {
+        let page = |pagination: Option<protos::Pagination>| ListResponse {
+            vectors: vec![],
+            pagination,
+            namespace: "".to_string(),
+            usage: None,
+        };
+
+        assert_eq!(next_pagination_token(&page(None)), None);
+        assert_eq!(
+            next_pagination_token(&page(Some(protos::Pagination {
+                next: "".to_string()
+            }))),
+            None
+        );
+        assert_eq!(
+            next_pagination_token(&page(Some(protos::Pagination {
+                next: "token".to_string()
+            }))),
+            Some("token".to_string())
+        );
+    }
 }
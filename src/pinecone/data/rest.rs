@@ -0,0 +1,627 @@
+use crate::protos;
+#[cfg(feature = "serde")]
+use crate::utils::errors::PineconeError;
+use reqwest::Method;
+#[cfg(feature = "serde")]
+use reqwest::StatusCode;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+#[cfg(feature = "serde")]
+use std::time::Duration;
+#[cfg(feature = "serde")]
+use tonic::metadata::MetadataValue;
+
+/// A client for the REST API of an index, which sends the same requests and returns the same responses as the
+/// gRPC `VectorServiceClient`.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone)]
+pub(super) struct RestClient {
+    client: ClientWithMiddleware,
+    /// The base URL of the index, such as `https://index-host:443`.
+    host: String,
+    api_key: String,
+    user_agent: Option<String>,
+}
+
+/// The messages of the data plane are converted to and from the JSON of the REST API by their serde
+/// implementations, which the `serde` feature derives. Without it, the REST transport is not available.
+#[cfg(feature = "serde")]
+pub(super) trait Json: Serialize + DeserializeOwned {}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize + DeserializeOwned> Json for T {}
+
+/// Without the `serde` feature, the messages have no JSON representation and the REST transport is not available.
+#[cfg(not(feature = "serde"))]
+pub(super) trait Json {}
+
+#[cfg(not(feature = "serde"))]
+impl<T> Json for T {}
+
+/// A data plane request that can be sent to the REST API of an index.
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+pub(super) trait RestRequest: Json {
+    /// The gRPC response that the JSON response is parsed into.
+    type Response: Json;
+
+    /// The path of the endpoint, relative to the host of the index.
+    const PATH: &'static str;
+
+    /// Builds the HTTP request for the index at `host`. Requests are sent as JSON bodies unless they override it.
+    fn build(&self, client: &ClientWithMiddleware, host: &str) -> RequestBuilder {
+        let builder = client.request(Method::POST, format!("{host}{}", Self::PATH));
+
+        #[cfg(feature = "serde")]
+        let builder = builder.json(self);
+
+        builder
+    }
+}
+
+#[cfg(feature = "serde")]
+impl RestClient {
+    pub(super) fn new(
+        client: ClientWithMiddleware,
+        host: String,
+        api_key: String,
+        user_agent: Option<String>,
+    ) -> Self {
+        RestClient {
+            client,
+            host,
+            api_key,
+            user_agent,
+        }
+    }
+
+    /// Sends `request` and returns its response. `timeout` bounds the whole request, and is reported as an expired deadline.
+    pub(super) async fn send<Req: RestRequest>(
+        &self,
+        request: &Req,
+        timeout: Option<Duration>,
+    ) -> Result<Req::Response, PineconeError> {
+        let mut builder = request.build(&self.client, &self.host);
+        if !self.api_key.is_empty() {
+            builder = builder.header("Api-Key", &self.api_key);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.header(reqwest::header::USER_AGENT, user_agent);
+        }
+        if let Some(timeout) = timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| request_error(e, timeout))?;
+
+        let status = response.status();
+        let request_id = ["x-pinecone-request-id", "x-request-id"]
+            .iter()
+            .find_map(|key| response.headers().get(*key)?.to_str().ok())
+            .map(str::to_string);
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| request_error(e.into(), timeout))?;
+
+        if !status.is_success() {
+            return Err(status_error(status, &body, request_id));
+        }
+
+        parse_json(&body)
+    }
+}
+
+#[cfg(feature = "serde")]
+// Helper function to map a failed request, whose timeout may have expired
+fn request_error(error: reqwest_middleware::Error, timeout: Option<Duration>) -> PineconeError {
+    match error {
+        reqwest_middleware::Error::Reqwest(e) if e.is_timeout() && timeout.is_some() => {
+            PineconeError::DataPlaneDeadlineExceededError {
+                status: tonic::Status::deadline_exceeded(e.to_string()),
+            }
+        }
+        reqwest_middleware::Error::Reqwest(e) => PineconeError::ReqwestError { source: e.into() },
+        reqwest_middleware::Error::Middleware(e) => PineconeError::ReqwestError { source: e },
+    }
+}
+
+#[cfg(feature = "serde")]
+/// The body of an error response: a gRPC status code and message.
+#[derive(Deserialize)]
+struct ErrorBody {
+    code: Option<i32>,
+    message: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+// Helper function to convert an error response to the data plane error of the equivalent gRPC status,
+// so that errors are the same with either transport
+fn status_error(status: StatusCode, body: &[u8], request_id: Option<String>) -> PineconeError {
+    let parsed = serde_json::from_slice::<ErrorBody>(body).ok();
+    let code = match parsed.as_ref().and_then(|body| body.code) {
+        Some(code) if code != 0 => tonic::Code::from(code),
+        _ => grpc_code(status),
+    };
+    let message = parsed
+        .and_then(|body| body.message)
+        .unwrap_or_else(|| String::from_utf8_lossy(body).to_string());
+
+    let mut status = tonic::Status::new(code, message);
+    if let Some(value) = request_id.and_then(|id| MetadataValue::try_from(id).ok()) {
+        status.metadata_mut().insert("x-pinecone-request-id", value);
+    }
+
+    status.into()
+}
+
+#[cfg(feature = "serde")]
+// Maps an HTTP status to the gRPC status code it is translated from
+fn grpc_code(status: StatusCode) -> tonic::Code {
+    match status {
+        StatusCode::BAD_REQUEST => tonic::Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => tonic::Code::Unauthenticated,
+        StatusCode::FORBIDDEN => tonic::Code::PermissionDenied,
+        StatusCode::NOT_FOUND => tonic::Code::NotFound,
+        StatusCode::CONFLICT => tonic::Code::AlreadyExists,
+        StatusCode::PRECONDITION_FAILED => tonic::Code::FailedPrecondition,
+        StatusCode::TOO_MANY_REQUESTS => tonic::Code::ResourceExhausted,
+        StatusCode::NOT_IMPLEMENTED => tonic::Code::Unimplemented,
+        StatusCode::SERVICE_UNAVAILABLE => tonic::Code::Unavailable,
+        StatusCode::GATEWAY_TIMEOUT => tonic::Code::DeadlineExceeded,
+        status if status.is_server_error() => tonic::Code::Internal,
+        _ => tonic::Code::Unknown,
+    }
+}
+
+// Helper function to parse a JSON response body
+#[cfg(feature = "serde")]
+fn parse_json<T: DeserializeOwned>(body: &[u8]) -> Result<T, PineconeError> {
+    // empty bodies, such as those of update and delete, are parsed as empty objects
+    let body = if body.is_empty() { b"{}" } else { body };
+    serde_json::from_slice(body).map_err(|e| PineconeError::SerdeError { source: e.into() })
+}
+
+impl RestRequest for protos::UpsertRequest {
+    type Response = protos::UpsertResponse;
+    const PATH: &'static str = "/vectors/upsert";
+}
+
+impl RestRequest for protos::QueryRequest {
+    type Response = protos::QueryResponse;
+    const PATH: &'static str = "/query";
+}
+
+impl RestRequest for protos::FetchRequest {
+    type Response = protos::FetchResponse;
+    const PATH: &'static str = "/vectors/fetch";
+
+    // the IDs are repeated query parameters, which the URL encoding of the message does not support
+    fn build(&self, client: &ClientWithMiddleware, host: &str) -> RequestBuilder {
+        let mut query: Vec<(&str, &str)> = self.ids.iter().map(|id| ("ids", id.as_str())).collect();
+        query.push(("namespace", &self.namespace));
+
+        client
+            .request(Method::GET, format!("{host}{}", Self::PATH))
+            .query(&query)
+    }
+}
+
+impl RestRequest for protos::ListRequest {
+    type Response = protos::ListResponse;
+    const PATH: &'static str = "/vectors/list";
+
+    fn build(&self, client: &ClientWithMiddleware, host: &str) -> RequestBuilder {
+        let builder = client.request(Method::GET, format!("{host}{}", Self::PATH));
+
+        #[cfg(feature = "serde")]
+        let builder = builder.query(self);
+
+        builder
+    }
+}
+
+impl RestRequest for protos::UpdateRequest {
+    type Response = protos::UpdateResponse;
+    const PATH: &'static str = "/vectors/update";
+}
+
+impl RestRequest for protos::DeleteRequest {
+    type Response = protos::DeleteResponse;
+    const PATH: &'static str = "/vectors/delete";
+}
+
+impl RestRequest for protos::DescribeIndexStatsRequest {
+    type Response = protos::DescribeIndexStatsResponse;
+    const PATH: &'static str = "/describe_index_stats";
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::models::{
+        json_to_metadata, DataPlaneTransport, Kind, Metadata, Namespace, Value, Vector,
+    };
+    use crate::pinecone::data::Index;
+    use crate::pinecone::PineconeClientConfig;
+    use httpmock::prelude::*;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use tokio;
+
+    async fn rest_index(server: &MockServer) -> Index {
+        PineconeClientConfig::builder()
+            .api_key("api_key")
+            .data_plane_transport(DataPlaneTransport::Rest)
+            .client()
+            .expect("Failed to create Pinecone instance")
+            .index(&server.base_url())
+            .await
+            .expect("Failed to target index")
+    }
+
+    fn metadata(genre: &str) -> Metadata {
+        Metadata {
+            fields: BTreeMap::from([(
+                "genre".to_string(),
+                Value {
+                    kind: Some(Kind::StringValue(genre.to_string())),
+                },
+            )]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upsert() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/vectors/upsert")
+                .header("Api-Key", "api_key")
+                .json_body(json!({
+                    "vectors": [{
+                        "id": "1",
+                        "values": [1.0, 2.0],
+                        "sparseValues": {"indices": [0, 3], "values": [0.5, 0.25]},
+                        "metadata": {"genre": "comedy"}
+                    }],
+                    "namespace": "ns"
+                }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(r#"{"upsertedCount": 1}"#);
+        });
+
+        let vectors = [Vector {
+            id: "1".to_string(),
+            values: vec![1.0, 2.0],
+            sparse_values: Some(protos::SparseValues {
+                indices: vec![0, 3],
+                values: vec![0.5, 0.25],
+            }),
+            metadata: Some(metadata("comedy")),
+        }];
+
+        let response = rest_index(&server)
+            .await
+            .upsert(&vectors, &"ns".into())
+            .await
+            .expect("Failed to upsert");
+
+        mock.assert();
+        assert_eq!(response.upserted_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/query").json_body(json!({
+                "namespace": "ns",
+                "topK": 2,
+                "filter": {"genre": {"$eq": "comedy"}},
+                "includeValues": true,
+                "includeMetadata": true,
+                "vector": [1.0, 2.0]
+            }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{
+                        "matches": [
+                            {"id": "1", "score": 0.5, "values": [1.0, 2.0], "metadata": {"genre": "comedy"}},
+                            {"id": "2", "score": 0.25, "values": [2.0, 1.0]}
+                        ],
+                        "namespace": "ns",
+                        "usage": {"readUnits": 5}
+                    }"#,
+                );
+        });
+
+        let filter = json_to_metadata(&json!({"genre": {"$eq": "comedy"}})).unwrap();
+        let response = rest_index(&server)
+            .await
+            .query_by_value(
+                vec![1.0, 2.0],
+                None,
+                2,
+                &"ns".into(),
+                Some(filter),
+                Some(true),
+                Some(true),
+            )
+            .await
+            .expect("Failed to query");
+
+        mock.assert();
+        assert_eq!(response.matches.len(), 2);
+        assert_eq!(response.matches[0].id, "1");
+        assert_eq!(response.matches[0].score, 0.5);
+        assert_eq!(response.matches[0].metadata, Some(metadata("comedy")));
+        assert_eq!(response.matches[1].metadata, None);
+        assert_eq!(response.namespace, "ns");
+        assert_eq!(
+            response.usage,
+            Some(protos::Usage {
+                read_units: Some(5)
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/vectors/fetch")
+                .query_param("ids", "1")
+                .query_param("ids", "2")
+                .query_param("namespace", "ns");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{
+                        "vectors": {
+                            "1": {"id": "1", "values": [1.0, 2.0], "metadata": {"genre": "comedy"}}
+                        },
+                        "namespace": "ns",
+                        "usage": {"readUnits": 1}
+                    }"#,
+                );
+        });
+
+        let response = rest_index(&server)
+            .await
+            .fetch(&["1", "2"], &"ns".into())
+            .await
+            .expect("Failed to fetch");
+
+        mock.assert();
+        assert_eq!(
+            response.vectors.get("1"),
+            Some(&Vector {
+                id: "1".to_string(),
+                values: vec![1.0, 2.0],
+                sparse_values: None,
+                metadata: Some(metadata("comedy")),
+            })
+        );
+        assert_eq!(
+            response.usage,
+            Some(protos::Usage {
+                read_units: Some(1)
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/vectors/list")
+                .query_param("namespace", "ns")
+                .query_param("prefix", "doc1#")
+                .query_param("limit", "2")
+                .query_param("paginationToken", "token");
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{
+                        "vectors": [{"id": "doc1#1"}, {"id": "doc1#2"}],
+                        "pagination": {"next": "next-token"},
+                        "namespace": "ns",
+                        "usage": {"readUnits": 1}
+                    }"#,
+                );
+        });
+
+        let response = rest_index(&server)
+            .await
+            .list(&"ns".into(), Some("doc1#"), Some(2), Some("token"))
+            .await
+            .expect("Failed to list");
+
+        mock.assert();
+        let ids: Vec<_> = response
+            .vectors
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect();
+        assert_eq!(ids, ["doc1#1", "doc1#2"]);
+        assert_eq!(
+            response.pagination,
+            Some(protos::Pagination {
+                next: "next-token".to_string()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/vectors/update").json_body(json!({
+                "id": "1",
+                "values": [3.0, 4.0],
+                "setMetadata": {"genre": "drama"},
+                "namespace": "ns"
+            }));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body("{}");
+        });
+
+        rest_index(&server)
+            .await
+            .update(
+                "1",
+                vec![3.0, 4.0],
+                None,
+                Some(metadata("drama")),
+                &"ns".into(),
+            )
+            .await
+            .expect("Failed to update");
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let server = MockServer::start();
+        let by_id = server.mock(|when, then| {
+            when.method(POST).path("/vectors/delete").json_body(json!({
+                "ids": ["1", "2"],
+                "deleteAll": false,
+                "namespace": "ns"
+            }));
+            then.status(200).body("{}");
+        });
+        let all = server.mock(|when, then| {
+            when.method(POST).path("/vectors/delete").json_body(json!({
+                "deleteAll": true,
+                "namespace": "ns"
+            }));
+            then.status(200);
+        });
+
+        let index = rest_index(&server).await;
+        index
+            .delete_by_id(&["1", "2"], &"ns".into())
+            .await
+            .expect("Failed to delete by id");
+        index
+            .delete_all(&"ns".into())
+            .await
+            .expect("Failed to delete all");
+
+        by_id.assert();
+        all.assert();
+    }
+
+    #[tokio::test]
+    async fn test_describe_index_stats() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/describe_index_stats")
+                .json_body(json!({}));
+            then.status(200)
+                .header("content-type", "application/json")
+                .body(
+                    r#"{
+                        "namespaces": {"": {"vectorCount": 2}, "ns": {"vectorCount": 3}},
+                        "dimension": 4,
+                        "indexFullness": 0.5,
+                        "totalVectorCount": 5
+                    }"#,
+                );
+        });
+
+        let response = rest_index(&server)
+            .await
+            .describe_index_stats(None)
+            .await
+            .expect("Failed to describe index stats");
+
+        mock.assert();
+        assert_eq!(response.dimension, 4);
+        assert_eq!(response.index_fullness, 0.5);
+        assert_eq!(response.total_vector_count, 5);
+        assert_eq!(response.namespaces.get("ns").unwrap().vector_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_error_response() {
+        let server = MockServer::start();
+        let _mock = server.mock(|when, then| {
+            when.method(GET).path("/vectors/fetch");
+            then.status(404)
+                .header("content-type", "application/json")
+                .header("x-pinecone-request-id", "request-id")
+                .body(r#"{"code": 5, "message": "Namespace not found", "details": []}"#);
+        });
+
+        let error = rest_index(&server)
+            .await
+            .fetch(&["1"], &Namespace::default())
+            .await
+            .expect_err("Expected fetch to fail");
+
+        match &error {
+            PineconeError::DataPlaneNotFoundError { status } => {
+                assert_eq!(status.message(), "Namespace not found");
+            }
+            error => panic!("Expected DataPlaneNotFoundError, got {:?}", error),
+        }
+        assert_eq!(error.request_id(), Some("request-id"));
+    }
+
+    #[tokio::test]
+    async fn test_error_response_without_code() {
+        let server = MockServer::start();
+        let _mock = server.mock(|when, then| {
+            when.method(POST).path("/vectors/upsert");
+            then.status(503).body("upstream connect error");
+        });
+
+        let error = rest_index(&server)
+            .await
+            .upsert(&[], &Namespace::default())
+            .await
+            .expect_err("Expected upsert to fail");
+
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneUnavailableError { .. }
+        ));
+        assert!(error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_deadline() {
+        let server = MockServer::start();
+        let _mock = server.mock(|when, then| {
+            when.method(POST).path("/vectors/upsert");
+            then.status(200)
+                .delay(Duration::from_secs(5))
+                .body(r#"{"upsertedCount": 0}"#);
+        });
+
+        let error = rest_index(&server)
+            .await
+            .with_deadline(Duration::from_millis(100))
+            .upsert(&[], &Namespace::default())
+            .await
+            .expect_err("Expected upsert to exceed its deadline");
+
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+    }
+}
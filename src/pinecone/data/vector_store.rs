@@ -0,0 +1,220 @@
+use super::Index;
+use crate::models::{
+    DescribeIndexStatsResponse, FetchResponse, ListResponse, Metadata, Namespace, QueryResponse,
+    SparseValues, UpdateResponse, UpsertResponse, Vector,
+};
+use crate::utils::errors::PineconeError;
+use async_trait::async_trait;
+
+/// The data plane operations of an index, so that code can depend on a vector store rather than on a connection to a
+/// Pinecone index.
+///
+/// `VectorStore` is implemented by `Index`, and by `InMemoryVectorStore` with the `testing` feature. It can be
+/// implemented by other backends, such as a mock, or a store that caches or shards the operations of other stores.
+/// Implementations must behave as `Index` does, including for the errors they return.
+///
+/// ### Example
+/// ```no_run
+/// use pinecone_sdk::models::{Namespace, QueryResponse};
+/// use pinecone_sdk::pinecone::data::VectorStore;
+/// use pinecone_sdk::utils::errors::PineconeError;
+///
+/// // Queries any vector store, such as an `Index`
+/// async fn similar(store: &dyn VectorStore, id: &str) -> Result<QueryResponse, PineconeError> {
+///     store.query_by_id(id, 10, &Namespace::default(), None, None, None).await
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), PineconeError>{
+/// let pinecone = pinecone_sdk::pinecone::default_client()?;
+///
+/// let index = pinecone.index("index-host").await?;
+/// let response = similar(&index, "vector-id").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait VectorStore: Send + Sync {
+    /// Upserts vectors into a namespace. See `Index::upsert`.
+    async fn upsert(
+        &self,
+        vectors: &[Vector],
+        namespace: &Namespace,
+    ) -> Result<UpsertResponse, PineconeError>;
+
+    /// Lists the IDs of vectors in a namespace, a page at a time. See `Index::list`.
+    async fn list(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+        limit: Option<u32>,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse, PineconeError>;
+
+    /// Returns statistics about the vectors of the store. See `Index::describe_index_stats`.
+    async fn describe_index_stats(
+        &self,
+        filter: Option<Metadata>,
+    ) -> Result<DescribeIndexStatsResponse, PineconeError>;
+
+    /// Updates the values or metadata of a vector. See `Index::update`.
+    async fn update(
+        &self,
+        id: &str,
+        values: Vec<f32>,
+        sparse_values: Option<SparseValues>,
+        metadata: Option<Metadata>,
+        namespace: &Namespace,
+    ) -> Result<UpdateResponse, PineconeError>;
+
+    /// Queries a namespace with the values of a stored vector. See `Index::query_by_id`.
+    async fn query_by_id(
+        &self,
+        id: &str,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError>;
+
+    /// Queries a namespace with a query vector. See `Index::query_by_value`.
+    #[allow(clippy::too_many_arguments)]
+    async fn query_by_value(
+        &self,
+        vector: Vec<f32>,
+        sparse_vector: Option<SparseValues>,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError>;
+
+    /// Deletes vectors by ID from a namespace. See `Index::delete_by_id`.
+    async fn delete_by_id(&self, ids: &[&str], namespace: &Namespace) -> Result<(), PineconeError>;
+
+    /// Deletes all vectors from a namespace. See `Index::delete_all`.
+    async fn delete_all(&self, namespace: &Namespace) -> Result<(), PineconeError>;
+
+    /// Deletes the vectors of a namespace whose metadata matches a filter. See `Index::delete_by_filter`.
+    async fn delete_by_filter(
+        &self,
+        filter: Metadata,
+        namespace: &Namespace,
+    ) -> Result<(), PineconeError>;
+
+    /// Fetches vectors by ID from a namespace. See `Index::fetch`.
+    async fn fetch(
+        &self,
+        ids: &[&str],
+        namespace: &Namespace,
+    ) -> Result<FetchResponse, PineconeError>;
+}
+
+#[async_trait]
+impl VectorStore for Index {
+    async fn upsert(
+        &self,
+        vectors: &[Vector],
+        namespace: &Namespace,
+    ) -> Result<UpsertResponse, PineconeError> {
+        Index::upsert(self, vectors, namespace).await
+    }
+
+    async fn list(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+        limit: Option<u32>,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse, PineconeError> {
+        Index::list(self, namespace, prefix, limit, pagination_token).await
+    }
+
+    async fn describe_index_stats(
+        &self,
+        filter: Option<Metadata>,
+    ) -> Result<DescribeIndexStatsResponse, PineconeError> {
+        Index::describe_index_stats(self, filter).await
+    }
+
+    async fn update(
+        &self,
+        id: &str,
+        values: Vec<f32>,
+        sparse_values: Option<SparseValues>,
+        metadata: Option<Metadata>,
+        namespace: &Namespace,
+    ) -> Result<UpdateResponse, PineconeError> {
+        Index::update(self, id, values, sparse_values, metadata, namespace).await
+    }
+
+    async fn query_by_id(
+        &self,
+        id: &str,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError> {
+        Index::query_by_id(
+            self,
+            id,
+            top_k,
+            namespace,
+            filter,
+            include_values,
+            include_metadata,
+        )
+        .await
+    }
+
+    async fn query_by_value(
+        &self,
+        vector: Vec<f32>,
+        sparse_vector: Option<SparseValues>,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError> {
+        Index::query_by_value(
+            self,
+            vector,
+            sparse_vector,
+            top_k,
+            namespace,
+            filter,
+            include_values,
+            include_metadata,
+        )
+        .await
+    }
+
+    async fn delete_by_id(&self, ids: &[&str], namespace: &Namespace) -> Result<(), PineconeError> {
+        Index::delete_by_id(self, ids, namespace).await
+    }
+
+    async fn delete_all(&self, namespace: &Namespace) -> Result<(), PineconeError> {
+        Index::delete_all(self, namespace).await
+    }
+
+    async fn delete_by_filter(
+        &self,
+        filter: Metadata,
+        namespace: &Namespace,
+    ) -> Result<(), PineconeError> {
+        Index::delete_by_filter(self, filter, namespace).await
+    }
+
+    async fn fetch(
+        &self,
+        ids: &[&str],
+        namespace: &Namespace,
+    ) -> Result<FetchResponse, PineconeError> {
+        Index::fetch(self, ids, namespace).await
+    }
+}
@@ -28,6 +28,7 @@ impl PineconeClient {
     /// # Ok(())
     /// # }
     /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(model = model, batch_size = inputs.len(), error.kind = tracing::field::Empty)))]
     pub async fn embed(
         &self,
         model: &str,
@@ -45,9 +46,16 @@ impl PineconeClient {
                 .collect(),
         };
 
-        let res = inference_api::embed(&self.openapi_config, Some(request))
-            .await
-            .map_err(PineconeError::from)?;
+        let res = self
+            .call("embed", || {
+                let request = request.clone();
+                async move {
+                    inference_api::embed(&self.openapi_config, Some(request))
+                        .await
+                        .map_err(PineconeError::from)
+                }
+            })
+            .await?;
 
         Ok(res.into())
     }
@@ -88,7 +96,7 @@ mod tests {
         let pinecone = config.client().expect("Failed to create Pinecone instance");
 
         let response = pinecone
-            .embed("multilingual-e5-large", None, &vec!["Hello, world!"])
+            .embed("multilingual-e5-large", None, &["Hello, world!"])
             .await
             .expect("Failed to embed");
 
@@ -137,7 +145,7 @@ mod tests {
             .embed(
                 "multilingual-e5-large",
                 Some(parameters),
-                &vec!["Hello, world!"],
+                &["Hello, world!"],
             )
             .await
             .expect_err("Expected to fail embedding with invalid arguments");
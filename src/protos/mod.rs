@@ -1,4 +1,6 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SparseValues {
     #[prost(uint32, repeated, packed = "false", tag = "1")]
@@ -6,6 +8,8 @@ pub struct SparseValues {
     #[prost(float, repeated, packed = "false", tag = "2")]
     pub values: ::prost::alloc::vec::Vec<f32>,
 }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Vector {
     /// This is the vector's unique id.
@@ -15,11 +19,21 @@ pub struct Vector {
     #[prost(float, repeated, packed = "false", tag = "2")]
     pub values: ::prost::alloc::vec::Vec<f32>,
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sparse_values: ::core::option::Option<SparseValues>,
     /// This is the metadata included in the request.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::models::metadata_json",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub metadata: ::core::option::Option<::prost_types::Struct>,
 }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScoredVector {
     /// This is the vector's unique id.
@@ -33,9 +47,17 @@ pub struct ScoredVector {
     pub values: ::prost::alloc::vec::Vec<f32>,
     /// This is the sparse data, if it is requested.
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sparse_values: ::core::option::Option<SparseValues>,
     /// This is the metadata, if it is requested.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::models::metadata_json",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub metadata: ::core::option::Option<::prost_types::Struct>,
 }
 /// This is a container to hold mutating vector requests. This is not actually used
@@ -58,6 +80,8 @@ pub mod request_union {
     }
 }
 /// The request for the `upsert` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpsertRequest {
     /// An array containing the vectors to upsert. Recommended batch limit is 100 vectors.
@@ -68,6 +92,8 @@ pub struct UpsertRequest {
     pub namespace: ::prost::alloc::string::String,
 }
 /// The response for the `upsert` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct UpsertResponse {
     /// The number of vectors upserted.
@@ -75,10 +101,13 @@ pub struct UpsertResponse {
     pub upserted_count: u32,
 }
 /// The request for the `Delete` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteRequest {
     /// Vectors to delete.
     #[prost(string, repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     /// This indicates that all vectors in the index namespace should be deleted.
     #[prost(bool, tag = "2")]
@@ -90,12 +119,23 @@ pub struct DeleteRequest {
     /// with specifying ids to delete in the ids param or using delete_all=True.
     /// See <https://docs.pinecone.io/guides/data/filtering-with-metadata.>
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::models::filter_json",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub filter: ::core::option::Option<::prost_types::Struct>,
 }
 /// The response for the `Delete` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DeleteResponse {}
 /// The request for the `fetch` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FetchRequest {
     /// The vector IDs to fetch. Does not accept values containing spaces.
@@ -105,6 +145,8 @@ pub struct FetchRequest {
     pub namespace: ::prost::alloc::string::String,
 }
 /// The response for the `fetch` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FetchResponse {
     /// The fetched vectors, in the form of a map between the fetched ids and the fetched vectors
@@ -115,34 +157,46 @@ pub struct FetchResponse {
     pub namespace: ::prost::alloc::string::String,
     ///   The usage for this operation.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub usage: ::core::option::Option<Usage>,
 }
 /// The request for the `List` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListRequest {
     /// The vector IDs to fetch. Does not accept values containing spaces.
     #[prost(string, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub prefix: ::core::option::Option<::prost::alloc::string::String>,
     /// Max number of ids to return
     #[prost(uint32, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub limit: ::core::option::Option<u32>,
     /// Pagination token to continue a previous listing operation
     #[prost(string, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pagination_token: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, tag = "4")]
     pub namespace: ::prost::alloc::string::String,
 }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pagination {
     #[prost(string, tag = "1")]
     pub next: ::prost::alloc::string::String,
 }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListItem {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
 }
 /// The response for the `List` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListResponse {
     /// A list of ids
@@ -150,15 +204,19 @@ pub struct ListResponse {
     pub vectors: ::prost::alloc::vec::Vec<ListItem>,
     /// Pagination token to continue past this listing
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub pagination: ::core::option::Option<Pagination>,
     /// The namespace of the vectors.
     #[prost(string, tag = "3")]
     pub namespace: ::prost::alloc::string::String,
     ///   The usage for this operation.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub usage: ::core::option::Option<Usage>,
 }
 /// A single query vector within a `QueryRequest`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryVector {
     /// The query vector values. This should be the same length as the dimension of the index being queried.
@@ -166,6 +224,7 @@ pub struct QueryVector {
     pub values: ::prost::alloc::vec::Vec<f32>,
     /// The query sparse values.
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sparse_values: ::core::option::Option<SparseValues>,
     /// An override for the number of results to return for this query vector.
     #[prost(uint32, tag = "2")]
@@ -175,9 +234,18 @@ pub struct QueryVector {
     pub namespace: ::prost::alloc::string::String,
     /// An override for the metadata filter to apply. This replaces the request-level filter.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::models::filter_json",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub filter: ::core::option::Option<::prost_types::Struct>,
 }
 /// The request for the `query` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryRequest {
     /// The namespace to query.
@@ -188,6 +256,13 @@ pub struct QueryRequest {
     pub top_k: u32,
     /// The filter to apply. You can use vector metadata to limit your search. See <https://www.pinecone.io/docs/metadata-filtering/.>
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::models::filter_json",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub filter: ::core::option::Option<::prost_types::Struct>,
     /// Indicates whether vector values are included in the response.
     #[prost(bool, tag = "4")]
@@ -198,18 +273,24 @@ pub struct QueryRequest {
     /// DEPRECATED. The query vectors. Each `query()` request can contain only one of the parameters `queries`, `vector`, or  `id`.
     #[deprecated]
     #[prost(message, repeated, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub queries: ::prost::alloc::vec::Vec<QueryVector>,
     /// The query vector. This should be the same length as the dimension of the index being queried. Each `query()` request can contain only one of the parameters `id` or `vector`.
     #[prost(float, repeated, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub vector: ::prost::alloc::vec::Vec<f32>,
     /// The query sparse values.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sparse_vector: ::core::option::Option<SparseValues>,
     /// The unique ID of the vector to be used as a query vector. Each `query()` request can contain only one of the parameters `queries`, `vector`, or  `id`.
     #[prost(string, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub id: ::prost::alloc::string::String,
 }
 /// The query results for a single `QueryVector`
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SingleQueryResults {
     /// The matches for the vectors.
@@ -220,11 +301,14 @@ pub struct SingleQueryResults {
     pub namespace: ::prost::alloc::string::String,
 }
 /// The response for the `query` operation. These are the matches found for a particular query vector. The matches are ordered from most similar to least similar.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryResponse {
     /// DEPRECATED. The results of each query. The order is the same as `QueryRequest.queries`.
     #[deprecated]
     #[prost(message, repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub results: ::prost::alloc::vec::Vec<SingleQueryResults>,
     /// The matches for the vectors.
     #[prost(message, repeated, tag = "2")]
@@ -234,15 +318,21 @@ pub struct QueryResponse {
     pub namespace: ::prost::alloc::string::String,
     /// The usage for this operation.
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub usage: ::core::option::Option<Usage>,
 }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Usage {
     /// The number of read units consumed by this operation.
     #[prost(uint32, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub read_units: ::core::option::Option<u32>,
 }
 /// The request for the `update` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateRequest {
     /// Vector's unique id.
@@ -250,29 +340,51 @@ pub struct UpdateRequest {
     pub id: ::prost::alloc::string::String,
     /// Vector data.
     #[prost(float, repeated, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
     pub values: ::prost::alloc::vec::Vec<f32>,
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub sparse_values: ::core::option::Option<SparseValues>,
     /// Metadata to *set* for the vector.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::models::metadata_json",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub set_metadata: ::core::option::Option<::prost_types::Struct>,
     /// Namespace name where to update the vector.
     #[prost(string, tag = "4")]
     pub namespace: ::prost::alloc::string::String,
 }
 /// The response for the `update` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct UpdateResponse {}
 /// The request for the `describe_index_stats` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DescribeIndexStatsRequest {
     /// If this parameter is present, the operation only returns statistics
     /// for vectors that satisfy the filter.
     /// See <https://docs.pinecone.io/guides/data/filtering-with-metadata.>
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::models::filter_json",
+            skip_serializing_if = "Option::is_none"
+        )
+    )]
     pub filter: ::core::option::Option<::prost_types::Struct>,
 }
 /// A summary of the contents of a namespace.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct NamespaceSummary {
     /// The number of vectors stored in this namespace. Note that updates to this field may lag behind updates to the
@@ -281,6 +393,8 @@ pub struct NamespaceSummary {
     pub vector_count: u32,
 }
 /// The response for the `describe_index_stats` operation.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DescribeIndexStatsResponse {
     /// A mapping for each namespace in the index from the namespace name to a
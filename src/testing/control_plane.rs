@@ -0,0 +1,877 @@
+use super::server::FakeServer;
+use crate::models::Metric;
+use crate::openapi::models::collection_model::Status as CollectionStatus;
+use crate::openapi::models::error_response_error::Code;
+use crate::openapi::models::index_model_status::State as IndexState;
+use crate::openapi::models::{
+    CollectionList, CollectionModel, ConfigureIndexRequest, CreateCollectionRequest,
+    CreateIndexRequest, DeletionProtection, ErrorResponse, ErrorResponseError, IndexList,
+    IndexModel, IndexModelSpec, IndexModelStatus,
+};
+use crate::utils::errors::PineconeError;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::time::Instant;
+
+// The largest dimension an index may have
+const MAX_DIMENSION: i32 = 20_000;
+
+// The longest name an index may have
+const MAX_INDEX_NAME_LENGTH: usize = 45;
+
+/// A fake Pinecone control plane, that manages indexes and collections in memory over HTTP.
+///
+/// Indexes go through the states of the real control plane: they are `Initializing` for the configured
+/// initialization delay after they are created, `ScalingUp`, `ScalingDown`, `ScalingUpPodSize` or
+/// `ScalingDownPodSize` for the scaling delay after their replicas or pod type are configured, and `Terminating` for
+/// the termination delay after they are deleted. Collections are `Initializing` for the initialization delay.
+/// All delays default to zero, so that indexes are ready as soon as they are described.
+///
+/// Deletion protection is enforced, and creating indexes and collections beyond the configured quotas fails like it
+/// does for a project over quota. Indexes are described with the host of the configured data plane, such as a
+/// `FakeDataPlane`, so that they can be targeted by name.
+///
+/// ### Example
+/// ```no_run
+/// use pinecone_sdk::models::{Cloud, DeletionProtection, Metric, WaitPolicy};
+/// use pinecone_sdk::pinecone::PineconeClientConfig;
+/// use pinecone_sdk::testing::{FakeControlPlane, FakeDataPlane};
+/// use std::time::Duration;
+/// # use pinecone_sdk::utils::errors::PineconeError;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), PineconeError>{
+/// let data_plane = FakeDataPlane::new(2, Metric::Cosine).start().await?;
+/// let control_plane = FakeControlPlane::new()
+///     .initialization_delay(Duration::from_millis(100))
+///     .data_plane_host(data_plane.host())
+///     .start()
+///     .await?;
+///
+/// let pinecone = PineconeClientConfig::builder()
+///     .api_key("api-key")
+///     .control_plane_host(control_plane.host())
+///     .client()?;
+///
+/// pinecone
+///     .create_serverless_index(
+///         "index-name",
+///         2,
+///         Metric::Cosine,
+///         Cloud::Aws,
+///         "us-east-1",
+///         DeletionProtection::Disabled,
+///         WaitPolicy::WaitFor(Duration::from_secs(1)),
+///     )
+///     .await?;
+/// let index = pinecone.index_by_name("index-name").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FakeControlPlane {
+    initialization_delay: Duration,
+    scaling_delay: Duration,
+    termination_delay: Duration,
+    index_quota: Option<usize>,
+    collection_quota: Option<usize>,
+    data_plane_host: Option<String>,
+    state: Arc<Mutex<ControlPlaneState>>,
+}
+
+// The indexes and collections of the project, by name
+#[derive(Debug, Default)]
+struct ControlPlaneState {
+    indexes: BTreeMap<String, Resource<IndexModel>>,
+    collections: BTreeMap<String, Resource<CollectionModel>>,
+}
+
+// An index or collection, and the time its current state ends at, if it is transitioning
+#[derive(Debug)]
+struct Resource<T> {
+    model: T,
+    transition_at: Option<Instant>,
+}
+
+// The time a transition that starts now ends at, or `None` if the delay is too long to be represented, such as
+// `Duration::MAX`, and the transition never ends
+fn transition_at(delay: Duration) -> Option<Instant> {
+    Instant::now().checked_add(delay)
+}
+
+impl ControlPlaneState {
+    // Completes the transitions that have ended: terminating indexes are removed, and other resources become ready
+    fn refresh(&mut self) {
+        let now = Instant::now();
+        let ended = |transition_at: Option<Instant>| transition_at.is_some_and(|at| at <= now);
+
+        self.indexes.retain(|_, index| {
+            if !ended(index.transition_at) {
+                return true;
+            }
+            index.transition_at = None;
+            if index.model.status.state == IndexState::Terminating {
+                return false;
+            }
+            *index.model.status = IndexModelStatus::new(true, IndexState::Ready);
+            true
+        });
+
+        for collection in self.collections.values_mut() {
+            if ended(collection.transition_at) {
+                collection.transition_at = None;
+                collection.model.status = CollectionStatus::Ready;
+            }
+        }
+    }
+}
+
+// An error response of the control plane
+struct ApiError {
+    status: StatusCode,
+    code: Code,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, code: Code, message: String) -> Self {
+        ApiError {
+            status,
+            code,
+            message,
+        }
+    }
+
+    fn bad_request(message: String) -> Self {
+        ApiError::new(StatusCode::BAD_REQUEST, Code::InvalidArgument, message)
+    }
+
+    fn index_not_found(name: &str) -> Self {
+        ApiError::new(
+            StatusCode::NOT_FOUND,
+            Code::NotFound,
+            format!("Index \"{name}\" not found"),
+        )
+    }
+
+    fn collection_not_found(name: &str) -> Self {
+        ApiError::new(
+            StatusCode::NOT_FOUND,
+            Code::NotFound,
+            format!("Collection \"{name}\" not found"),
+        )
+    }
+
+    fn already_exists(name: &str) -> Self {
+        ApiError::new(
+            StatusCode::CONFLICT,
+            Code::AlreadyExists,
+            format!("Resource \"{name}\" already exists"),
+        )
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorResponse::new(
+            self.status.as_u16().into(),
+            ErrorResponseError::new(self.code, self.message),
+        );
+        (self.status, Json(body)).into_response()
+    }
+}
+
+impl FakeControlPlane {
+    /// Creates a fake control plane without indexes or collections, whose resources transition immediately, and
+    /// without quotas.
+    ///
+    /// ### Return
+    /// * `FakeControlPlane`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long indexes and collections are `Initializing` after they are created.
+    pub fn initialization_delay(mut self, delay: Duration) -> Self {
+        self.initialization_delay = delay;
+        self
+    }
+
+    /// Sets how long indexes are scaling after their replicas or pod type are configured.
+    pub fn scaling_delay(mut self, delay: Duration) -> Self {
+        self.scaling_delay = delay;
+        self
+    }
+
+    /// Sets how long indexes are `Terminating` after they are deleted.
+    pub fn termination_delay(mut self, delay: Duration) -> Self {
+        self.termination_delay = delay;
+        self
+    }
+
+    /// Sets the largest number of indexes the project may have.
+    pub fn index_quota(mut self, quota: usize) -> Self {
+        self.index_quota = Some(quota);
+        self
+    }
+
+    /// Sets the largest number of collections the project may have.
+    pub fn collection_quota(mut self, quota: usize) -> Self {
+        self.collection_quota = Some(quota);
+        self
+    }
+
+    /// Sets the host that indexes are described with, such as the host of a `FakeDataPlane`. Default is a fake host
+    /// derived from the name of the index, which cannot be connected to.
+    pub fn data_plane_host(mut self, host: impl Into<String>) -> Self {
+        self.data_plane_host = Some(host.into());
+        self
+    }
+
+    /// Starts serving the control plane on an ephemeral port of the loopback interface, on the current Tokio runtime.
+    ///
+    /// ### Return
+    /// * `Result<FakeServer, PineconeError>` - The running server, which is shut down when dropped.
+    pub async fn start(&self) -> Result<FakeServer, PineconeError> {
+        let router = Router::new()
+            .route("/indexes", get(list_indexes).post(create_index))
+            .route(
+                "/indexes/:name",
+                get(describe_index)
+                    .patch(configure_index)
+                    .delete(delete_index),
+            )
+            .route(
+                "/collections",
+                get(list_collections).post(create_collection),
+            )
+            .route(
+                "/collections/:name",
+                get(describe_collection).delete(delete_collection),
+            )
+            .with_state(self.clone());
+
+        FakeServer::start(|listener, shutdown| async move {
+            // the server only fails if the listener does, which leaves nothing to report the error to
+            let _ = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown.await;
+                })
+                .await;
+        })
+        .await
+    }
+
+    // Locks the state, and completes the transitions that have ended
+    fn state(&self) -> MutexGuard<'_, ControlPlaneState> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.refresh();
+        state
+    }
+}
+
+async fn list_indexes(State(plane): State<FakeControlPlane>) -> Json<IndexList> {
+    let state = plane.state();
+    let indexes = state
+        .indexes
+        .values()
+        .map(|index| index.model.clone())
+        .collect();
+
+    Json(IndexList {
+        indexes: Some(indexes),
+    })
+}
+
+async fn create_index(
+    State(plane): State<FakeControlPlane>,
+    Json(request): Json<CreateIndexRequest>,
+) -> Result<(StatusCode, Json<IndexModel>), ApiError> {
+    let mut state = plane.state();
+
+    let name = request.name;
+    if name.is_empty()
+        || name.len() > MAX_INDEX_NAME_LENGTH
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(ApiError::bad_request(format!(
+            "Index name \"{name}\" must consist of at most {MAX_INDEX_NAME_LENGTH} lowercase alphanumeric characters or '-'"
+        )));
+    }
+    if !(1..=MAX_DIMENSION).contains(&request.dimension) {
+        return Err(ApiError::bad_request(format!(
+            "Dimension must be between 1 and {MAX_DIMENSION}"
+        )));
+    }
+    if state.indexes.contains_key(&name) {
+        return Err(ApiError::already_exists(&name));
+    }
+
+    let spec = request.spec.unwrap_or_default();
+    let spec = match (spec.serverless, spec.pod) {
+        (Some(serverless), None) => IndexModelSpec {
+            serverless: Some(serverless),
+            pod: None,
+        },
+        (None, Some(pod)) => {
+            if let Some(source) = &pod.source_collection {
+                if !state.collections.contains_key(source) {
+                    return Err(ApiError::collection_not_found(source));
+                }
+            }
+            IndexModelSpec {
+                serverless: None,
+                pod: Some(pod),
+            }
+        }
+        _ => {
+            return Err(ApiError::bad_request(
+                "Exactly one of the serverless and pod specs must be provided".to_string(),
+            ))
+        }
+    };
+
+    if let Some(quota) = plane.index_quota {
+        if state.indexes.len() >= quota {
+            return Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                Code::QuotaExceeded,
+                format!("Request failed. You've reached the max indexes allowed in the project ({quota})."),
+            ));
+        }
+    }
+
+    let model = IndexModel {
+        host: plane
+            .data_plane_host
+            .clone()
+            .unwrap_or_else(|| format!("{name}-fake.svc.pinecone.io")),
+        name: name.clone(),
+        dimension: request.dimension,
+        metric: Metric::from(request.metric.unwrap_or_default()).into(),
+        deletion_protection: Some(request.deletion_protection.unwrap_or_default()),
+        spec: Box::new(spec),
+        status: Box::new(IndexModelStatus::new(false, IndexState::Initializing)),
+    };
+    state.indexes.insert(
+        name,
+        Resource {
+            model: model.clone(),
+            transition_at: transition_at(plane.initialization_delay),
+        },
+    );
+
+    Ok((StatusCode::CREATED, Json(model)))
+}
+
+async fn describe_index(
+    State(plane): State<FakeControlPlane>,
+    Path(name): Path<String>,
+) -> Result<Json<IndexModel>, ApiError> {
+    let state = plane.state();
+    let index = state
+        .indexes
+        .get(&name)
+        .ok_or_else(|| ApiError::index_not_found(&name))?;
+
+    Ok(Json(index.model.clone()))
+}
+
+async fn configure_index(
+    State(plane): State<FakeControlPlane>,
+    Path(name): Path<String>,
+    Json(request): Json<ConfigureIndexRequest>,
+) -> Result<Json<IndexModel>, ApiError> {
+    let mut state = plane.state();
+    let index = state
+        .indexes
+        .get_mut(&name)
+        .ok_or_else(|| ApiError::index_not_found(&name))?;
+
+    if let Some(spec) = request.spec {
+        let pod = index.model.spec.pod.as_mut().ok_or_else(|| {
+            ApiError::bad_request(
+                "Replicas and pod type can only be configured for pod indexes".to_string(),
+            )
+        })?;
+
+        let mut scaling = None;
+        if let Some(replicas) = spec.pod.replicas {
+            if replicas < 1 {
+                return Err(ApiError::bad_request(
+                    "Replicas must be at least 1".to_string(),
+                ));
+            }
+            if replicas != pod.replicas {
+                scaling = Some(if replicas > pod.replicas {
+                    IndexState::ScalingUp
+                } else {
+                    IndexState::ScalingDown
+                });
+            }
+            pod.replicas = replicas;
+            pod.pods = pod.replicas * pod.shards;
+        }
+        if let Some(pod_type) = spec.pod.pod_type {
+            if pod_type != pod.pod_type {
+                scaling = Some(if pod_size(&pod_type) >= pod_size(&pod.pod_type) {
+                    IndexState::ScalingUpPodSize
+                } else {
+                    IndexState::ScalingDownPodSize
+                });
+            }
+            pod.pod_type = pod_type;
+        }
+
+        if let Some(scaling) = scaling {
+            // indexes keep serving requests while they scale
+            *index.model.status = IndexModelStatus::new(true, scaling);
+            index.transition_at = transition_at(plane.scaling_delay);
+        }
+    }
+    if let Some(deletion_protection) = request.deletion_protection {
+        index.model.deletion_protection = Some(deletion_protection);
+    }
+
+    Ok(Json(index.model.clone()))
+}
+
+// The size of a pod type, such as 2 for "p1.x2"
+fn pod_size(pod_type: &str) -> u32 {
+    pod_type
+        .rsplit_once(".x")
+        .and_then(|(_, size)| size.parse().ok())
+        .unwrap_or(1)
+}
+
+async fn delete_index(
+    State(plane): State<FakeControlPlane>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let mut state = plane.state();
+    let index = state
+        .indexes
+        .get_mut(&name)
+        .ok_or_else(|| ApiError::index_not_found(&name))?;
+
+    if index.model.deletion_protection == Some(DeletionProtection::Enabled) {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            Code::Forbidden,
+            format!("Deletion protection is enabled for index \"{name}\". Disable deletion protection before retrying."),
+        ));
+    }
+    if index.model.status.state != IndexState::Terminating {
+        *index.model.status = IndexModelStatus::new(false, IndexState::Terminating);
+        index.transition_at = transition_at(plane.termination_delay);
+    }
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn list_collections(State(plane): State<FakeControlPlane>) -> Json<CollectionList> {
+    let state = plane.state();
+    let collections = state
+        .collections
+        .values()
+        .map(|collection| collection.model.clone())
+        .collect();
+
+    Json(CollectionList {
+        collections: Some(collections),
+    })
+}
+
+async fn create_collection(
+    State(plane): State<FakeControlPlane>,
+    Json(request): Json<CreateCollectionRequest>,
+) -> Result<(StatusCode, Json<CollectionModel>), ApiError> {
+    let mut state = plane.state();
+
+    if state.collections.contains_key(&request.name) {
+        return Err(ApiError::already_exists(&request.name));
+    }
+    let source = state
+        .indexes
+        .get(&request.source)
+        .ok_or_else(|| ApiError::index_not_found(&request.source))?;
+
+    if let Some(quota) = plane.collection_quota {
+        if state.collections.len() >= quota {
+            return Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                Code::QuotaExceeded,
+                format!("Request failed. You've reached the max collections allowed in the project ({quota})."),
+            ));
+        }
+    }
+
+    let environment = match (&source.model.spec.pod, &source.model.spec.serverless) {
+        (Some(pod), _) => pod.environment.clone(),
+        (None, Some(serverless)) => serverless.region.clone(),
+        (None, None) => String::new(),
+    };
+    let model = CollectionModel {
+        name: request.name.clone(),
+        size: None,
+        status: CollectionStatus::Initializing,
+        dimension: Some(source.model.dimension),
+        vector_count: None,
+        environment,
+    };
+    state.collections.insert(
+        request.name,
+        Resource {
+            model: model.clone(),
+            transition_at: transition_at(plane.initialization_delay),
+        },
+    );
+
+    Ok((StatusCode::CREATED, Json(model)))
+}
+
+async fn describe_collection(
+    State(plane): State<FakeControlPlane>,
+    Path(name): Path<String>,
+) -> Result<Json<CollectionModel>, ApiError> {
+    let state = plane.state();
+    let collection = state
+        .collections
+        .get(&name)
+        .ok_or_else(|| ApiError::collection_not_found(&name))?;
+
+    Ok(Json(collection.model.clone()))
+}
+
+async fn delete_collection(
+    State(plane): State<FakeControlPlane>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let mut state = plane.state();
+    state
+        .collections
+        .remove(&name)
+        .ok_or_else(|| ApiError::collection_not_found(&name))?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+#[cfg(all(test, feature = "control"))]
+mod tests {
+    use super::*;
+    use crate::models::{Cloud, State as IndexState, WaitPolicy};
+    use crate::pinecone::{PineconeClient, PineconeClientConfig};
+    use crate::testing::FakeDataPlane;
+    use tokio;
+
+    // Starts a fake control plane, and creates a client that targets it
+    async fn start(plane: FakeControlPlane) -> (FakeServer, PineconeClient) {
+        let server = plane
+            .start()
+            .await
+            .expect("Failed to start fake control plane");
+
+        let pinecone = PineconeClientConfig::builder()
+            .api_key("api-key")
+            .control_plane_host(server.host())
+            .client()
+            .expect("Failed to create Pinecone instance");
+
+        (server, pinecone)
+    }
+
+    async fn create_serverless_index(
+        pinecone: &PineconeClient,
+        name: &str,
+        deletion_protection: DeletionProtection,
+    ) -> Result<crate::models::IndexModel, PineconeError> {
+        pinecone
+            .create_serverless_index(
+                name,
+                2,
+                Metric::Cosine,
+                Cloud::Aws,
+                "us-east-1",
+                deletion_protection,
+                WaitPolicy::NoWait,
+            )
+            .await
+    }
+
+    async fn create_pod_index(pinecone: &PineconeClient, name: &str, source: Option<&str>) {
+        pinecone
+            .create_pod_index(
+                name,
+                2,
+                Metric::Euclidean,
+                "us-east-1-aws",
+                "p1.x1",
+                1,
+                1,
+                1,
+                DeletionProtection::Disabled,
+                None,
+                source,
+                WaitPolicy::NoWait,
+            )
+            .await
+            .expect("Failed to create pod index");
+    }
+
+    #[tokio::test]
+    async fn test_create_index_initializes() {
+        let plane = FakeControlPlane::new().initialization_delay(Duration::from_millis(200));
+        let (_server, pinecone) = start(plane).await;
+
+        let index = create_serverless_index(&pinecone, "index-name", DeletionProtection::Disabled)
+            .await
+            .unwrap();
+        assert_eq!(index.status.state, IndexState::Initializing);
+        assert!(!index.status.ready);
+        assert_eq!(index.metric, Metric::Cosine);
+
+        let index = pinecone.describe_index("index-name").await.unwrap();
+        assert_eq!(index.status.state, IndexState::Initializing);
+
+        // polls until the index is ready
+        pinecone
+            .create_serverless_index(
+                "other-index",
+                2,
+                Metric::Cosine,
+                Cloud::Aws,
+                "us-east-1",
+                DeletionProtection::Disabled,
+                WaitPolicy::WaitFor(Duration::from_millis(500)),
+            )
+            .await
+            .unwrap();
+        let index = pinecone.describe_index("other-index").await.unwrap();
+        assert_eq!(index.status.state, IndexState::Ready);
+        assert!(index.status.ready);
+
+        let indexes = pinecone.list_indexes().await.unwrap();
+        let names: Vec<String> = indexes
+            .indexes
+            .unwrap()
+            .into_iter()
+            .map(|index| index.name)
+            .collect();
+        assert_eq!(names, ["index-name", "other-index"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_index_initializes_forever() {
+        let plane = FakeControlPlane::new().initialization_delay(Duration::MAX);
+        let (_server, pinecone) = start(plane).await;
+
+        let index = create_serverless_index(&pinecone, "index-name", DeletionProtection::Disabled)
+            .await
+            .unwrap();
+        assert_eq!(index.status.state, IndexState::Initializing);
+
+        let index = pinecone.describe_index("index-name").await.unwrap();
+        assert_eq!(index.status.state, IndexState::Initializing);
+    }
+
+    #[tokio::test]
+    async fn test_create_index_invalid() {
+        let (_server, pinecone) = start(FakeControlPlane::new()).await;
+
+        create_serverless_index(&pinecone, "index-name", DeletionProtection::Disabled)
+            .await
+            .unwrap();
+        let error = create_serverless_index(&pinecone, "index-name", DeletionProtection::Disabled)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PineconeError::ResourceAlreadyExistsError { .. }
+        ));
+
+        let error = create_serverless_index(&pinecone, "Index_Name", DeletionProtection::Disabled)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, PineconeError::BadRequestError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_configure_index_scales() {
+        let plane = FakeControlPlane::new().scaling_delay(Duration::from_millis(200));
+        let (_server, pinecone) = start(plane).await;
+        create_pod_index(&pinecone, "index-name", None).await;
+
+        let index = pinecone
+            .configure_index("index-name", None, Some(2), None)
+            .await
+            .unwrap();
+        assert_eq!(index.status.state, IndexState::ScalingUp);
+        assert!(index.status.ready);
+        assert_eq!(index.spec.pod.as_ref().unwrap().replicas, 2);
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let index = pinecone.describe_index("index-name").await.unwrap();
+        assert_eq!(index.status.state, IndexState::Ready);
+
+        let index = pinecone
+            .configure_index("index-name", None, None, Some("p1.x2"))
+            .await
+            .unwrap();
+        assert_eq!(index.status.state, IndexState::ScalingUpPodSize);
+
+        create_serverless_index(&pinecone, "serverless", DeletionProtection::Disabled)
+            .await
+            .unwrap();
+        let error = pinecone
+            .configure_index("serverless", None, Some(2), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, PineconeError::BadRequestError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_delete_index() {
+        let plane = FakeControlPlane::new().termination_delay(Duration::from_millis(200));
+        let (_server, pinecone) = start(plane).await;
+        create_serverless_index(&pinecone, "index-name", DeletionProtection::Enabled)
+            .await
+            .unwrap();
+
+        let error = pinecone.delete_index("index-name").await.unwrap_err();
+        assert!(matches!(error, PineconeError::ActionForbiddenError { .. }));
+
+        pinecone
+            .configure_index("index-name", Some(DeletionProtection::Disabled), None, None)
+            .await
+            .unwrap();
+        pinecone.delete_index("index-name").await.unwrap();
+
+        let index = pinecone.describe_index("index-name").await.unwrap();
+        assert_eq!(index.status.state, IndexState::Terminating);
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let error = pinecone.describe_index("index-name").await.unwrap_err();
+        assert!(matches!(error, PineconeError::IndexNotFoundError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_not_found_names() {
+        let (_server, pinecone) = start(FakeControlPlane::new()).await;
+
+        // names that mention other resources do not change the kind of resource that was not found
+        for name in ["my-cloud-index", "us-east-region", "collection-backup"] {
+            let error = pinecone.describe_index(name).await.unwrap_err();
+            assert!(matches!(error, PineconeError::IndexNotFoundError { .. }));
+
+            let error = pinecone
+                .create_collection("collection-name", name)
+                .await
+                .unwrap_err();
+            assert!(matches!(error, PineconeError::IndexNotFoundError { .. }));
+        }
+
+        let error = pinecone
+            .describe_collection("index-cloud-region")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PineconeError::CollectionNotFoundError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_quotas() {
+        let plane = FakeControlPlane::new().index_quota(1).collection_quota(1);
+        let (_server, pinecone) = start(plane).await;
+        create_pod_index(&pinecone, "index-name", None).await;
+
+        let error = create_serverless_index(&pinecone, "other-index", DeletionProtection::Disabled)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, PineconeError::PodQuotaExceededError { .. }));
+
+        pinecone
+            .create_collection("collection-name", "index-name")
+            .await
+            .unwrap();
+        let error = pinecone
+            .create_collection("other-collection", "index-name")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PineconeError::CollectionsQuotaExceededError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_collections() {
+        let plane = FakeControlPlane::new().initialization_delay(Duration::from_millis(200));
+        let (_server, pinecone) = start(plane).await;
+        create_pod_index(&pinecone, "index-name", None).await;
+
+        let collection = pinecone
+            .create_collection("collection-name", "index-name")
+            .await
+            .unwrap();
+        assert_eq!(collection.status, CollectionStatus::Initializing);
+        assert_eq!(collection.dimension, Some(2));
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let collection = pinecone
+            .describe_collection("collection-name")
+            .await
+            .unwrap();
+        assert_eq!(collection.status, CollectionStatus::Ready);
+
+        // indexes can be created from collections
+        create_pod_index(&pinecone, "restored-index", Some("collection-name")).await;
+
+        let collections = pinecone.list_collections().await.unwrap();
+        assert_eq!(collections.collections.unwrap().len(), 1);
+
+        pinecone.delete_collection("collection-name").await.unwrap();
+        let error = pinecone
+            .describe_collection("collection-name")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PineconeError::CollectionNotFoundError { .. }
+        ));
+
+        let error = pinecone
+            .create_collection("collection-name", "missing-index")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, PineconeError::IndexNotFoundError { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_data_plane_host() {
+        let data_plane = FakeDataPlane::new(2, Metric::Cosine).start().await.unwrap();
+        let plane = FakeControlPlane::new().data_plane_host(data_plane.host());
+        let (_server, pinecone) = start(plane).await;
+        create_serverless_index(&pinecone, "index-name", DeletionProtection::Disabled)
+            .await
+            .unwrap();
+
+        let index = pinecone.index_by_name("index-name").await.unwrap();
+        let vectors = [crate::models::Vector {
+            id: "id1".to_string(),
+            values: vec![1.0, 2.0],
+            ..Default::default()
+        }];
+        let response = index.upsert(&vectors, &Default::default()).await.unwrap();
+        assert_eq!(response.upserted_count, 1);
+    }
+}
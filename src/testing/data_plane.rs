@@ -0,0 +1,477 @@
+use super::engine::VectorEngine;
+use super::server::FakeServer;
+use super::vector_store::InMemoryVectorStore;
+use crate::models::Metric;
+use crate::protos::vector_service_server::{VectorService, VectorServiceServer};
+use crate::protos::{
+    DeleteRequest, DeleteResponse, DescribeIndexStatsRequest, DescribeIndexStatsResponse,
+    FetchRequest, FetchResponse, ListRequest, ListResponse, QueryRequest, QueryResponse,
+    UpdateRequest, UpdateResponse, UpsertRequest, UpsertResponse,
+};
+use crate::utils::errors::PineconeError;
+use std::sync::Arc;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+/// A fake Pinecone data plane, that serves an in-memory index over gRPC.
+///
+/// The index supports namespaces, upserting, fetching, updating, deleting and listing vectors, exact queries with the
+/// index metric, and index statistics. Queries, deletes and statistics may be filtered by metadata with the `$eq`,
+/// `$ne`, `$gt`, `$gte`, `$lt`, `$lte`, `$in`, `$nin`, `$exists`, `$and` and `$or` operators.
+///
+/// Clones of a `FakeDataPlane` share the same index, so its contents may be inspected or seeded with `vector_store()`
+/// while it is being served.
+///
+/// ### Example
+/// ```no_run
+/// use pinecone_sdk::models::{Metric, Vector};
+/// use pinecone_sdk::pinecone::PineconeClientConfig;
+/// use pinecone_sdk::testing::FakeDataPlane;
+/// # use pinecone_sdk::utils::errors::PineconeError;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), PineconeError>{
+/// let server = FakeDataPlane::new(2, Metric::Cosine).start().await?;
+///
+/// let pinecone = PineconeClientConfig {
+///     api_key: Some("api-key".to_string()),
+///     ..Default::default()
+/// }
+/// .client()?;
+/// let index = pinecone.index(&server.host()).await?;
+///
+/// let vectors = [Vector {
+///     id: "id1".to_string(),
+///     values: vec![1.0, 2.0],
+///     ..Default::default()
+/// }];
+/// index.upsert(&vectors, &"namespace".into()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FakeDataPlane {
+    engine: Arc<VectorEngine>,
+}
+
+impl FakeDataPlane {
+    /// Creates a fake data plane serving an empty index.
+    ///
+    /// ### Arguments
+    /// * `dimension: u32` - The dimension of the vectors of the index.
+    /// * `metric: Metric` - The distance metric that queries rank vectors with.
+    ///
+    /// ### Return
+    /// * `FakeDataPlane`
+    pub fn new(dimension: u32, metric: Metric) -> Self {
+        FakeDataPlane {
+            engine: Arc::new(VectorEngine::new(dimension, metric)),
+        }
+    }
+
+    /// Returns a vector store with the vectors of the index, which reads and writes the index without going through
+    /// the server.
+    ///
+    /// ### Return
+    /// * `InMemoryVectorStore`
+    pub fn vector_store(&self) -> InMemoryVectorStore {
+        InMemoryVectorStore::from_engine(self.engine.clone())
+    }
+
+    /// Starts serving the index on an ephemeral port of the loopback interface, on the current Tokio runtime.
+    ///
+    /// ### Return
+    /// * `Result<FakeServer, PineconeError>` - The running server, which is shut down when dropped.
+    pub async fn start(&self) -> Result<FakeServer, PineconeError> {
+        let service = VectorServiceServer::new(self.clone());
+
+        FakeServer::start(|listener, shutdown| async move {
+            // the server only fails if the listener does, which leaves nothing to report the error to
+            let _ = Server::builder()
+                .add_service(service)
+                .serve_with_incoming_shutdown(TcpListenerStream::new(listener), async {
+                    let _ = shutdown.await;
+                })
+                .await;
+        })
+        .await
+    }
+}
+
+#[tonic::async_trait]
+impl VectorService for FakeDataPlane {
+    async fn upsert(
+        &self,
+        request: Request<UpsertRequest>,
+    ) -> Result<Response<UpsertResponse>, Status> {
+        self.engine.upsert(request.into_inner()).map(Response::new)
+    }
+
+    async fn delete(
+        &self,
+        request: Request<DeleteRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        self.engine.delete(request.into_inner()).map(Response::new)
+    }
+
+    async fn fetch(
+        &self,
+        request: Request<FetchRequest>,
+    ) -> Result<Response<FetchResponse>, Status> {
+        self.engine.fetch(request.into_inner()).map(Response::new)
+    }
+
+    async fn list(&self, request: Request<ListRequest>) -> Result<Response<ListResponse>, Status> {
+        self.engine.list(request.into_inner()).map(Response::new)
+    }
+
+    async fn query(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> Result<Response<QueryResponse>, Status> {
+        self.engine.query(request.into_inner()).map(Response::new)
+    }
+
+    async fn update(
+        &self,
+        request: Request<UpdateRequest>,
+    ) -> Result<Response<UpdateResponse>, Status> {
+        self.engine.update(request.into_inner()).map(Response::new)
+    }
+
+    async fn describe_index_stats(
+        &self,
+        request: Request<DescribeIndexStatsRequest>,
+    ) -> Result<Response<DescribeIndexStatsResponse>, Status> {
+        self.engine
+            .describe_index_stats(request.into_inner())
+            .map(Response::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{json_to_metadata, Filter, Namespace, SparseValues, Vector};
+    use crate::pinecone::data::Index;
+    use crate::pinecone::PineconeClientConfig;
+    use futures::TryStreamExt;
+    use serde_json::json;
+    use tokio;
+
+    // Starts a fake data plane, and connects an index to it
+    async fn start(dimension: u32, metric: Metric) -> (FakeServer, Index) {
+        let server = FakeDataPlane::new(dimension, metric)
+            .start()
+            .await
+            .expect("Failed to start fake data plane");
+
+        let pinecone = PineconeClientConfig {
+            api_key: Some("api-key".to_string()),
+            ..Default::default()
+        }
+        .client()
+        .expect("Failed to create Pinecone instance");
+        let index = pinecone
+            .index(&server.host())
+            .await
+            .expect("Failed to target index");
+
+        (server, index)
+    }
+
+    fn vector(id: &str, values: Vec<f32>, metadata: serde_json::Value) -> Vector {
+        Vector {
+            id: id.to_string(),
+            values,
+            sparse_values: None,
+            metadata: Some(json_to_metadata(&metadata).unwrap()),
+        }
+    }
+
+    fn movies() -> Vec<Vector> {
+        vec![
+            vector(
+                "movie1",
+                vec![1.0, 0.0],
+                json!({"genre": "comedy", "year": 2020}),
+            ),
+            vector(
+                "movie2",
+                vec![0.0, 1.0],
+                json!({"genre": "drama", "year": 2010}),
+            ),
+            vector(
+                "movie3",
+                vec![1.0, 1.0],
+                json!({"genre": "comedy", "year": 2000}),
+            ),
+        ]
+    }
+
+    fn ids(response: &QueryResponse) -> Vec<&str> {
+        response
+            .matches
+            .iter()
+            .map(|scored| scored.id.as_str())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_upsert_and_fetch() {
+        let (_server, index) = start(2, Metric::Cosine).await;
+        let namespace = Namespace::from("movies");
+
+        let response = index.upsert(&movies(), &namespace).await.unwrap();
+        assert_eq!(response.upserted_count, 3);
+
+        let response = index
+            .fetch(&["movie1", "missing"], &namespace)
+            .await
+            .unwrap();
+        assert_eq!(response.vectors.len(), 1);
+        assert_eq!(response.vectors["movie1"], movies()[0]);
+        assert_eq!(response.namespace, "movies");
+
+        let response = index
+            .fetch(&["movie1"], &Namespace::default())
+            .await
+            .unwrap();
+        assert!(response.vectors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_upsert_invalid_dimension() {
+        let (_server, index) = start(3, Metric::Cosine).await;
+
+        let error = index
+            .upsert(&movies(), &Namespace::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneInvalidArgumentError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update() {
+        let (_server, index) = start(2, Metric::Cosine).await;
+        let namespace = Namespace::default();
+        index.upsert(&movies(), &namespace).await.unwrap();
+
+        let metadata = json_to_metadata(&json!({"year": 2021, "rating": 8})).unwrap();
+        index
+            .update("movie1", vec![0.5, 0.5], None, Some(metadata), &namespace)
+            .await
+            .unwrap();
+
+        let response = index.fetch(&["movie1"], &namespace).await.unwrap();
+        assert_eq!(
+            response.vectors["movie1"],
+            vector(
+                "movie1",
+                vec![0.5, 0.5],
+                json!({"genre": "comedy", "year": 2021, "rating": 8})
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let (_server, index) = start(2, Metric::Cosine).await;
+        let namespace = Namespace::from("movies");
+        index.upsert(&movies(), &namespace).await.unwrap();
+
+        index.delete_by_id(&["movie1"], &namespace).await.unwrap();
+        let filter = Filter::eq("genre", "drama").build().unwrap();
+        index.delete_by_filter(filter, &namespace).await.unwrap();
+
+        let response = index
+            .fetch(&["movie1", "movie2", "movie3"], &namespace)
+            .await
+            .unwrap();
+        assert_eq!(response.vectors.keys().collect::<Vec<_>>(), ["movie3"]);
+
+        index.delete_all(&namespace).await.unwrap();
+        let response = index.describe_index_stats(None).await.unwrap();
+        assert_eq!(response.total_vector_count, 0);
+        assert!(response.namespaces.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list() {
+        let (_server, index) = start(2, Metric::Cosine).await;
+        let namespace = Namespace::default();
+        let vectors: Vec<Vector> = ["doc1#2", "doc2#1", "doc1#1", "doc1#3"]
+            .iter()
+            .map(|id| vector(id, vec![1.0, 1.0], json!({})))
+            .collect();
+        index.upsert(&vectors, &namespace).await.unwrap();
+
+        let response = index
+            .list(&namespace, Some("doc1#"), Some(2), None)
+            .await
+            .unwrap();
+        let page: Vec<&str> = response
+            .vectors
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect();
+        assert_eq!(page, ["doc1#1", "doc1#2"]);
+        let token = response.pagination.expect("Expected pagination").next;
+
+        let response = index
+            .list(&namespace, Some("doc1#"), Some(2), Some(&token))
+            .await
+            .unwrap();
+        let page: Vec<&str> = response
+            .vectors
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect();
+        assert_eq!(page, ["doc1#3"]);
+        assert!(response.pagination.is_none());
+
+        let all: Vec<String> = index
+            .list_all(&namespace, None, None)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(all, ["doc1#1", "doc1#2", "doc1#3", "doc2#1"]);
+    }
+
+    #[tokio::test]
+    async fn test_query_cosine() {
+        let (_server, index) = start(2, Metric::Cosine).await;
+        let namespace = Namespace::default();
+        index.upsert(&movies(), &namespace).await.unwrap();
+
+        let response = index
+            .query_by_value(vec![2.0, 0.0], None, 2, &namespace, None, None, Some(true))
+            .await
+            .unwrap();
+        assert_eq!(ids(&response), ["movie1", "movie3"]);
+        assert!((response.matches[0].score - 1.0).abs() < 1e-6);
+        assert!((response.matches[1].score - 0.5_f32.sqrt()).abs() < 1e-6);
+        assert!(response.matches[0].values.is_empty());
+        assert!(response.matches[0].metadata.is_some());
+
+        let response = index
+            .query_by_id("movie2", 3, &namespace, None, Some(true), None)
+            .await
+            .unwrap();
+        assert_eq!(ids(&response), ["movie2", "movie3", "movie1"]);
+        assert_eq!(response.matches[0].values, vec![0.0, 1.0]);
+        assert!(response.matches[0].metadata.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_query_euclidean() {
+        let (_server, index) = start(2, Metric::Euclidean).await;
+        let namespace = Namespace::default();
+        index.upsert(&movies(), &namespace).await.unwrap();
+
+        let response = index
+            .query_by_value(vec![1.0, 0.2], None, 3, &namespace, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(ids(&response), ["movie1", "movie3", "movie2"]);
+        assert!((response.matches[0].score - 0.04).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_query_dotproduct() {
+        let (_server, index) = start(2, Metric::Dotproduct).await;
+        let namespace = Namespace::default();
+        let mut vectors = movies();
+        vectors[1].sparse_values = Some(SparseValues {
+            indices: vec![7],
+            values: vec![10.0],
+        });
+        index.upsert(&vectors, &namespace).await.unwrap();
+
+        let sparse_vector = SparseValues {
+            indices: vec![7, 8],
+            values: vec![1.0, 1.0],
+        };
+        let response = index
+            .query_by_value(
+                vec![1.0, 0.5],
+                Some(sparse_vector),
+                3,
+                &namespace,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ids(&response), ["movie2", "movie3", "movie1"]);
+        assert_eq!(response.matches[0].score, 10.5);
+    }
+
+    #[tokio::test]
+    async fn test_query_filter() {
+        let (_server, index) = start(2, Metric::Cosine).await;
+        let namespace = Namespace::default();
+        index.upsert(&movies(), &namespace).await.unwrap();
+
+        let filter = Filter::and([Filter::eq("genre", "comedy"), Filter::lt("year", 2010)])
+            .build()
+            .unwrap();
+        let response = index
+            .query_by_value(
+                vec![1.0, 0.0],
+                None,
+                10,
+                &namespace,
+                Some(filter),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ids(&response), ["movie3"]);
+
+        let filter = json_to_metadata(&json!({"genre": {"$regex": "com.*"}})).unwrap();
+        let error = index
+            .query_by_value(
+                vec![1.0, 0.0],
+                None,
+                10,
+                &namespace,
+                Some(filter),
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneInvalidArgumentError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_describe_index_stats() {
+        let (_server, index) = start(2, Metric::Cosine).await;
+        index.upsert(&movies(), &"movies".into()).await.unwrap();
+        index
+            .upsert(&movies()[..1], &Namespace::default())
+            .await
+            .unwrap();
+
+        let response = index.describe_index_stats(None).await.unwrap();
+        assert_eq!(response.dimension, 2);
+        assert_eq!(response.total_vector_count, 4);
+        assert_eq!(response.namespaces["movies"].vector_count, 3);
+        assert_eq!(response.namespaces[""].vector_count, 1);
+
+        let filter = Filter::eq("genre", "drama").build().unwrap();
+        let response = index.describe_index_stats(Some(filter)).await.unwrap();
+        assert_eq!(response.total_vector_count, 1);
+        assert_eq!(response.namespaces.len(), 1);
+    }
+}
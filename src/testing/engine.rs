@@ -0,0 +1,405 @@
+use super::filter::MetadataFilter;
+use crate::models::Metric;
+use crate::protos::{
+    DeleteRequest, DeleteResponse, DescribeIndexStatsRequest, DescribeIndexStatsResponse,
+    FetchRequest, FetchResponse, ListItem, ListRequest, ListResponse, NamespaceSummary, Pagination,
+    QueryRequest, QueryResponse, ScoredVector, SparseValues, UpdateRequest, UpdateResponse,
+    UpsertRequest, UpsertResponse, Usage, Vector,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use tonic::Status;
+
+// The number of vector IDs returned by a list request without a limit
+const DEFAULT_LIST_LIMIT: u32 = 100;
+
+// The largest number of results a query may request
+const MAX_TOP_K: u32 = 10_000;
+
+// The read units reported for fetch, list and query requests
+const READ_UNITS: u32 = 1;
+
+// The vectors of a namespace, sorted by ID
+type Namespace = BTreeMap<String, Vector>;
+
+/// An in-memory index, that stores vectors by namespace and searches them exhaustively.
+///
+/// Requests are validated and fail with the status Pinecone would return, such as `InvalidArgument` for vectors
+/// whose dimension does not match the index.
+#[derive(Debug)]
+pub(crate) struct VectorEngine {
+    dimension: u32,
+    metric: Metric,
+    namespaces: Mutex<HashMap<String, Namespace>>,
+}
+
+impl VectorEngine {
+    pub(crate) fn new(dimension: u32, metric: Metric) -> Self {
+        VectorEngine {
+            dimension,
+            metric,
+            namespaces: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // A panic while the lock is held cannot leave a namespace half-updated, so a poisoned lock is still usable
+    fn namespaces(&self) -> MutexGuard<'_, HashMap<String, Namespace>> {
+        self.namespaces
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(crate) fn upsert(&self, request: UpsertRequest) -> Result<UpsertResponse, Status> {
+        if request.vectors.is_empty() {
+            return Err(Status::invalid_argument("No vectors provided"));
+        }
+        for vector in &request.vectors {
+            if vector.id.is_empty() {
+                return Err(Status::invalid_argument("Vector ID must not be empty"));
+            }
+            self.validate_values(&vector.values, vector.sparse_values.as_ref())?;
+        }
+
+        let upserted_count = request.vectors.len() as u32;
+        let mut namespaces = self.namespaces();
+        let namespace = namespaces.entry(request.namespace).or_default();
+        for vector in request.vectors {
+            namespace.insert(vector.id.clone(), vector);
+        }
+
+        Ok(UpsertResponse { upserted_count })
+    }
+
+    pub(crate) fn fetch(&self, request: FetchRequest) -> Result<FetchResponse, Status> {
+        if request.ids.is_empty() {
+            return Err(Status::invalid_argument("No IDs provided"));
+        }
+
+        let namespaces = self.namespaces();
+        let vectors = match namespaces.get(&request.namespace) {
+            Some(namespace) => request
+                .ids
+                .iter()
+                .filter_map(|id| namespace.get(id).map(|vector| (id.clone(), vector.clone())))
+                .collect(),
+            None => HashMap::new(),
+        };
+
+        Ok(FetchResponse {
+            vectors,
+            namespace: request.namespace,
+            usage: Some(usage()),
+        })
+    }
+
+    pub(crate) fn update(&self, request: UpdateRequest) -> Result<UpdateResponse, Status> {
+        if request.id.is_empty() {
+            return Err(Status::invalid_argument("Vector ID must not be empty"));
+        }
+        if !request.values.is_empty() {
+            self.validate_values(&request.values, None)?;
+        }
+        if let Some(sparse_values) = &request.sparse_values {
+            validate_sparse_values(sparse_values)?;
+        }
+
+        // like Pinecone, updating a vector that does not exist is not an error
+        let mut namespaces = self.namespaces();
+        let Some(vector) = namespaces
+            .get_mut(&request.namespace)
+            .and_then(|namespace| namespace.get_mut(&request.id))
+        else {
+            return Ok(UpdateResponse {});
+        };
+
+        if !request.values.is_empty() {
+            vector.values = request.values;
+        }
+        if request.sparse_values.is_some() {
+            vector.sparse_values = request.sparse_values;
+        }
+        if let Some(set_metadata) = request.set_metadata {
+            vector
+                .metadata
+                .get_or_insert_with(Default::default)
+                .fields
+                .extend(set_metadata.fields);
+        }
+
+        Ok(UpdateResponse {})
+    }
+
+    pub(crate) fn delete(&self, request: DeleteRequest) -> Result<DeleteResponse, Status> {
+        let mut namespaces = self.namespaces();
+
+        match (request.ids.is_empty(), request.delete_all, &request.filter) {
+            (false, false, None) => {
+                if let Some(namespace) = namespaces.get_mut(&request.namespace) {
+                    for id in &request.ids {
+                        namespace.remove(id);
+                    }
+                }
+            }
+            (true, true, None) => {
+                namespaces.remove(&request.namespace);
+            }
+            (true, false, Some(filter)) => {
+                let filter = MetadataFilter::parse(filter)?;
+                if let Some(namespace) = namespaces.get_mut(&request.namespace) {
+                    namespace.retain(|_, vector| !filter.matches(vector.metadata.as_ref()));
+                }
+            }
+            _ => {
+                return Err(Status::invalid_argument(
+                    "Exactly one of ids, delete_all and filter must be provided",
+                ))
+            }
+        }
+
+        // namespaces exist only while they contain vectors
+        namespaces.retain(|_, namespace| !namespace.is_empty());
+
+        Ok(DeleteResponse {})
+    }
+
+    pub(crate) fn list(&self, request: ListRequest) -> Result<ListResponse, Status> {
+        let limit = request.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+        if limit == 0 {
+            return Err(Status::invalid_argument("limit must be greater than 0"));
+        }
+        let prefix = request.prefix.unwrap_or_default();
+        // the pagination token is the last ID of the previous page
+        let after = request.pagination_token.filter(|token| !token.is_empty());
+
+        let namespaces = self.namespaces();
+        let mut ids: Vec<&String> = namespaces
+            .get(&request.namespace)
+            .into_iter()
+            .flat_map(|namespace| namespace.keys())
+            .filter(|id| id.starts_with(&prefix))
+            .filter(|id| after.as_ref().map_or(true, |after| *id > after))
+            .take(limit as usize + 1)
+            .collect();
+
+        let pagination = if ids.len() > limit as usize {
+            ids.truncate(limit as usize);
+            ids.last().map(|id| Pagination {
+                next: id.to_string(),
+            })
+        } else {
+            None
+        };
+
+        Ok(ListResponse {
+            vectors: ids
+                .into_iter()
+                .map(|id| ListItem { id: id.clone() })
+                .collect(),
+            pagination,
+            namespace: request.namespace,
+            usage: Some(usage()),
+        })
+    }
+
+    #[allow(deprecated)]
+    pub(crate) fn query(&self, request: QueryRequest) -> Result<QueryResponse, Status> {
+        if request.top_k == 0 || request.top_k > MAX_TOP_K {
+            return Err(Status::invalid_argument(format!(
+                "top_k must be between 1 and {MAX_TOP_K}"
+            )));
+        }
+        if !request.queries.is_empty() {
+            return Err(Status::unimplemented("Batch queries are not supported"));
+        }
+        let filter = request
+            .filter
+            .as_ref()
+            .map(MetadataFilter::parse)
+            .transpose()?;
+
+        let namespaces = self.namespaces();
+        let namespace = namespaces.get(&request.namespace);
+
+        let (values, sparse_values) = match (request.id.is_empty(), request.vector.is_empty()) {
+            (false, true) if request.sparse_vector.is_none() => {
+                match namespace.and_then(|namespace| namespace.get(&request.id)) {
+                    Some(vector) => (vector.values.clone(), vector.sparse_values.clone()),
+                    None => (vec![], None),
+                }
+            }
+            (true, false) => {
+                self.validate_values(&request.vector, request.sparse_vector.as_ref())?;
+                (request.vector, request.sparse_vector)
+            }
+            _ => {
+                return Err(Status::invalid_argument(
+                    "Exactly one of vector and id must be provided",
+                ))
+            }
+        };
+
+        // querying by the ID of a vector that does not exist matches no vectors
+        let mut matches: Vec<ScoredVector> = if values.is_empty() {
+            vec![]
+        } else {
+            namespace
+                .into_iter()
+                .flat_map(|namespace| namespace.values())
+                .filter(|vector| {
+                    filter
+                        .as_ref()
+                        .map_or(true, |filter| filter.matches(vector.metadata.as_ref()))
+                })
+                .map(|vector| ScoredVector {
+                    id: vector.id.clone(),
+                    score: self.score(&values, sparse_values.as_ref(), vector),
+                    values: if request.include_values {
+                        vector.values.clone()
+                    } else {
+                        vec![]
+                    },
+                    sparse_values: vector
+                        .sparse_values
+                        .clone()
+                        .filter(|_| request.include_values),
+                    metadata: vector.metadata.clone().filter(|_| request.include_metadata),
+                })
+                .collect()
+        };
+
+        // the most similar vectors have the highest score, except for euclidean distance
+        matches.sort_by(|a, b| match self.metric {
+            Metric::Euclidean => a.score.total_cmp(&b.score),
+            _ => b.score.total_cmp(&a.score),
+        });
+        matches.truncate(request.top_k as usize);
+
+        Ok(QueryResponse {
+            results: vec![],
+            matches,
+            namespace: request.namespace,
+            usage: Some(usage()),
+        })
+    }
+
+    pub(crate) fn describe_index_stats(
+        &self,
+        request: DescribeIndexStatsRequest,
+    ) -> Result<DescribeIndexStatsResponse, Status> {
+        let filter = request
+            .filter
+            .as_ref()
+            .map(MetadataFilter::parse)
+            .transpose()?;
+
+        let namespaces: HashMap<String, NamespaceSummary> = self
+            .namespaces()
+            .iter()
+            .map(|(name, namespace)| {
+                let vector_count = namespace
+                    .values()
+                    .filter(|vector| {
+                        filter
+                            .as_ref()
+                            .map_or(true, |filter| filter.matches(vector.metadata.as_ref()))
+                    })
+                    .count() as u32;
+                (name.clone(), NamespaceSummary { vector_count })
+            })
+            .filter(|(_, summary)| summary.vector_count > 0)
+            .collect();
+
+        Ok(DescribeIndexStatsResponse {
+            total_vector_count: namespaces
+                .values()
+                .map(|summary| summary.vector_count)
+                .sum(),
+            namespaces,
+            dimension: self.dimension,
+            index_fullness: 0.0,
+        })
+    }
+
+    fn validate_values(
+        &self,
+        values: &[f32],
+        sparse_values: Option<&SparseValues>,
+    ) -> Result<(), Status> {
+        if values.len() != self.dimension as usize {
+            return Err(Status::invalid_argument(format!(
+                "Vector dimension {} does not match the dimension of the index {}",
+                values.len(),
+                self.dimension
+            )));
+        }
+        if let Some(sparse_values) = sparse_values {
+            validate_sparse_values(sparse_values)?;
+            if self.metric != Metric::Dotproduct {
+                return Err(Status::invalid_argument(
+                    "Sparse values are supported only for indexes with the dotproduct metric",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    // The similarity of a vector to the query
+    fn score(&self, values: &[f32], sparse_values: Option<&SparseValues>, vector: &Vector) -> f32 {
+        let dot = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(a, b)| a * b).sum::<f32>();
+
+        match self.metric {
+            Metric::Cosine => {
+                let norm = dot(values, values).sqrt() * dot(&vector.values, &vector.values).sqrt();
+                if norm == 0.0 {
+                    0.0
+                } else {
+                    dot(values, &vector.values) / norm
+                }
+            }
+            // Pinecone reports the squared euclidean distance
+            Metric::Euclidean => values
+                .iter()
+                .zip(&vector.values)
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum(),
+            Metric::Dotproduct => {
+                let sparse_dot = match (sparse_values, &vector.sparse_values) {
+                    (Some(query), Some(vector)) => sparse_dot(query, vector),
+                    _ => 0.0,
+                };
+                dot(values, &vector.values) + sparse_dot
+            }
+        }
+    }
+}
+
+fn validate_sparse_values(sparse_values: &SparseValues) -> Result<(), Status> {
+    if sparse_values.indices.len() != sparse_values.values.len() {
+        return Err(Status::invalid_argument(
+            "Sparse values must have as many indices as values",
+        ));
+    }
+
+    Ok(())
+}
+
+fn sparse_dot(a: &SparseValues, b: &SparseValues) -> f32 {
+    let b: HashMap<u32, f32> = b
+        .indices
+        .iter()
+        .copied()
+        .zip(b.values.iter().copied())
+        .collect();
+    a.indices
+        .iter()
+        .zip(&a.values)
+        .filter_map(|(index, value)| b.get(index).map(|other| value * other))
+        .sum()
+}
+
+fn usage() -> Usage {
+    Usage {
+        read_units: Some(READ_UNITS),
+    }
+}
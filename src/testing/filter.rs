@@ -0,0 +1,248 @@
+use prost_types::{value::Kind, Struct, Value};
+use tonic::Status;
+
+/// A metadata filter, parsed from the `Metadata` of a request and evaluated against the metadata of vectors.
+///
+/// The `$eq`, `$ne`, `$gt`, `$gte`, `$lt`, `$lte`, `$in`, `$nin` and `$exists` operators are supported, combined with
+/// `$and` and `$or`. A field compared to a value without an operator is compared with `$eq`. Fields whose value is a
+/// list match `$eq` and `$in` if any of their elements does.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum MetadataFilter {
+    /// Matches if all filters match
+    And(Vec<MetadataFilter>),
+    /// Matches if any filter matches
+    Or(Vec<MetadataFilter>),
+    /// Matches if the value of the field satisfies the operator
+    Field { field: String, operator: Operator },
+}
+
+/// An operator of a field of a `MetadataFilter`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Operator {
+    Eq(Kind),
+    Ne(Kind),
+    Gt(f64),
+    Gte(f64),
+    Lt(f64),
+    Lte(f64),
+    In(Vec<Kind>),
+    Nin(Vec<Kind>),
+    Exists(bool),
+}
+
+fn invalid_filter(message: String) -> Status {
+    Status::invalid_argument(format!("Invalid filter: {message}"))
+}
+
+impl MetadataFilter {
+    /// Parses a filter, and fails with an `InvalidArgument` status if it is malformed or uses an unsupported operator.
+    pub(crate) fn parse(filter: &Struct) -> Result<Self, Status> {
+        filter
+            .fields
+            .iter()
+            .map(|(key, value)| match key.as_str() {
+                "$and" => Ok(MetadataFilter::And(Self::parse_list(key, value)?)),
+                "$or" => Ok(MetadataFilter::Or(Self::parse_list(key, value)?)),
+                operator if operator.starts_with('$') => {
+                    Err(invalid_filter(format!("unsupported operator {operator}")))
+                }
+                field => Self::parse_field(field, value),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(MetadataFilter::And)
+    }
+
+    fn parse_list(operator: &str, value: &Value) -> Result<Vec<Self>, Status> {
+        match &value.kind {
+            Some(Kind::ListValue(list)) => list
+                .values
+                .iter()
+                .map(|value| match &value.kind {
+                    Some(Kind::StructValue(filter)) => Self::parse(filter),
+                    _ => Err(invalid_filter(format!(
+                        "{operator} must be a list of filters"
+                    ))),
+                })
+                .collect(),
+            _ => Err(invalid_filter(format!(
+                "{operator} must be a list of filters"
+            ))),
+        }
+    }
+
+    fn parse_field(field: &str, value: &Value) -> Result<Self, Status> {
+        let condition = |operator| MetadataFilter::Field {
+            field: field.to_string(),
+            operator,
+        };
+
+        match &value.kind {
+            Some(Kind::StructValue(operators)) => operators
+                .fields
+                .iter()
+                .map(|(operator, operand)| Ok(condition(Operator::parse(operator, operand)?)))
+                .collect::<Result<Vec<_>, _>>()
+                .map(MetadataFilter::And),
+            _ => Ok(condition(Operator::Eq(scalar(field, value)?))),
+        }
+    }
+
+    /// Returns whether vector metadata matches the filter.
+    pub(crate) fn matches(&self, metadata: Option<&Struct>) -> bool {
+        match self {
+            MetadataFilter::And(filters) => filters.iter().all(|filter| filter.matches(metadata)),
+            MetadataFilter::Or(filters) => filters.iter().any(|filter| filter.matches(metadata)),
+            MetadataFilter::Field { field, operator } => operator.matches(
+                metadata
+                    .and_then(|metadata| metadata.fields.get(field))
+                    .and_then(|value| value.kind.as_ref()),
+            ),
+        }
+    }
+}
+
+impl Operator {
+    fn parse(operator: &str, operand: &Value) -> Result<Self, Status> {
+        match operator {
+            "$eq" => Ok(Operator::Eq(scalar(operator, operand)?)),
+            "$ne" => Ok(Operator::Ne(scalar(operator, operand)?)),
+            "$gt" => Ok(Operator::Gt(number(operator, operand)?)),
+            "$gte" => Ok(Operator::Gte(number(operator, operand)?)),
+            "$lt" => Ok(Operator::Lt(number(operator, operand)?)),
+            "$lte" => Ok(Operator::Lte(number(operator, operand)?)),
+            "$in" => Ok(Operator::In(list(operator, operand)?)),
+            "$nin" => Ok(Operator::Nin(list(operator, operand)?)),
+            "$exists" => match operand.kind {
+                Some(Kind::BoolValue(exists)) => Ok(Operator::Exists(exists)),
+                _ => Err(invalid_filter(format!("{operator} must be a boolean"))),
+            },
+            _ => Err(invalid_filter(format!("unsupported operator {operator}"))),
+        }
+    }
+
+    fn matches(&self, value: Option<&Kind>) -> bool {
+        // the elements of a list field, or the value of a scalar field
+        let values: Vec<&Kind> = match value {
+            Some(Kind::ListValue(list)) => list
+                .values
+                .iter()
+                .filter_map(|value| value.kind.as_ref())
+                .collect(),
+            Some(kind) => vec![kind],
+            None => vec![],
+        };
+        let any_number = |predicate: &dyn Fn(f64) -> bool| {
+            values
+                .iter()
+                .any(|value| matches!(value, Kind::NumberValue(n) if predicate(*n)))
+        };
+
+        match self {
+            Operator::Eq(operand) => values.contains(&operand),
+            Operator::Ne(operand) => !values.contains(&operand),
+            Operator::Gt(operand) => any_number(&|n| n > *operand),
+            Operator::Gte(operand) => any_number(&|n| n >= *operand),
+            Operator::Lt(operand) => any_number(&|n| n < *operand),
+            Operator::Lte(operand) => any_number(&|n| n <= *operand),
+            Operator::In(operands) => values.iter().any(|value| operands.contains(value)),
+            Operator::Nin(operands) => !values.iter().any(|value| operands.contains(value)),
+            Operator::Exists(exists) => value.is_some() == *exists,
+        }
+    }
+}
+
+fn scalar(operator: &str, value: &Value) -> Result<Kind, Status> {
+    match &value.kind {
+        Some(kind @ (Kind::StringValue(_) | Kind::NumberValue(_) | Kind::BoolValue(_))) => {
+            Ok(kind.clone())
+        }
+        _ => Err(invalid_filter(format!(
+            "{operator} must be a string, number or boolean"
+        ))),
+    }
+}
+
+fn number(operator: &str, value: &Value) -> Result<f64, Status> {
+    match value.kind {
+        Some(Kind::NumberValue(n)) => Ok(n),
+        _ => Err(invalid_filter(format!("{operator} must be a number"))),
+    }
+}
+
+fn list(operator: &str, value: &Value) -> Result<Vec<Kind>, Status> {
+    match &value.kind {
+        Some(Kind::ListValue(list)) => list
+            .values
+            .iter()
+            .map(|value| scalar(operator, value))
+            .collect(),
+        _ => Err(invalid_filter(format!("{operator} must be a list"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{json_to_metadata, Filter};
+    use serde_json::json;
+
+    fn metadata(value: serde_json::Value) -> Struct {
+        json_to_metadata(&value).unwrap()
+    }
+
+    #[test]
+    fn test_matches() {
+        let movie =
+            metadata(json!({"genre": "comedy", "year": 2020, "tags": ["family", "holiday"]}));
+
+        let matches = |filter: Filter| {
+            MetadataFilter::parse(&filter.build().unwrap())
+                .unwrap()
+                .matches(Some(&movie))
+        };
+
+        assert!(matches(Filter::eq("genre", "comedy")));
+        assert!(!matches(Filter::ne("genre", "comedy")));
+        assert!(matches(Filter::eq("tags", "family")));
+        assert!(matches(Filter::gte("year", 2020)));
+        assert!(!matches(Filter::gt("year", 2020)));
+        assert!(matches(Filter::lt("year", 2021)));
+        assert!(matches(Filter::is_in("genre", ["drama", "comedy"])));
+        assert!(!matches(Filter::not_in("tags", ["holiday"])));
+        assert!(matches(Filter::exists("genre", true)));
+        assert!(matches(Filter::exists("rating", false)));
+        assert!(matches(Filter::and([
+            Filter::eq("genre", "comedy"),
+            Filter::or([Filter::eq("year", 1999), Filter::eq("tags", "holiday")]),
+        ])));
+        assert!(!matches(Filter::and([
+            Filter::eq("genre", "comedy"),
+            Filter::eq("year", 1999),
+        ])));
+    }
+
+    #[test]
+    fn test_matches_implicit_eq() {
+        let filter =
+            MetadataFilter::parse(&metadata(json!({"genre": "comedy", "year": {"$gt": 2000}})))
+                .unwrap();
+
+        assert!(filter.matches(Some(&metadata(json!({"genre": "comedy", "year": 2020})))));
+        assert!(!filter.matches(Some(&metadata(json!({"genre": "comedy", "year": 1990})))));
+        assert!(!filter.matches(None));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for filter in [
+            json!({"genre": {"$regex": "com.*"}}),
+            json!({"$not": {"genre": "comedy"}}),
+            json!({"year": {"$gt": "2020"}}),
+            json!({"genre": {"$in": "comedy"}}),
+            json!({"$and": {"genre": "comedy"}}),
+        ] {
+            let status = MetadataFilter::parse(&metadata(filter)).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+mod control_plane;
+pub use self::control_plane::FakeControlPlane;
+
+mod data_plane;
+pub use self::data_plane::FakeDataPlane;
+
+mod server;
+pub use self::server::FakeServer;
+
+mod vector_store;
+pub use self::vector_store::InMemoryVectorStore;
+
+mod engine;
+
+mod filter;
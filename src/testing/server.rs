@@ -0,0 +1,52 @@
+use crate::utils::errors::PineconeError;
+use std::future::Future;
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+/// A fake Pinecone service, running in the background on an ephemeral port of the loopback interface.
+///
+/// The service is shut down when the `FakeServer` is dropped.
+#[derive(Debug)]
+pub struct FakeServer {
+    addr: SocketAddr,
+    // Closing the channel signals the service to shut down
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl FakeServer {
+    /// Binds an ephemeral port of the loopback interface, and spawns `serve` on the current runtime to serve it
+    /// until the returned `FakeServer` is dropped.
+    pub(crate) async fn start<F, Fut>(serve: F) -> Result<Self, PineconeError>
+    where
+        F: FnOnce(TcpListener, oneshot::Receiver<()>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let io_error = |e: std::io::Error| PineconeError::IoError {
+            message: format!("Failed to bind fake server: {e}"),
+        };
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(io_error)?;
+        let addr = listener.local_addr().map_err(io_error)?;
+
+        let (shutdown, signal) = oneshot::channel();
+        tokio::spawn(serve(listener, signal));
+
+        Ok(FakeServer {
+            addr,
+            _shutdown: shutdown,
+        })
+    }
+
+    /// Returns the address the service listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the plaintext URL of the service, such as `http://127.0.0.1:50123`.
+    pub fn host(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
@@ -0,0 +1,325 @@
+use super::engine::VectorEngine;
+use crate::models::{
+    DescribeIndexStatsResponse, FetchResponse, ListResponse, Metadata, Metric, Namespace,
+    QueryResponse, SparseValues, UpdateResponse, UpsertResponse, Vector,
+};
+use crate::pinecone::data::VectorStore;
+use crate::protos;
+use crate::utils::errors::PineconeError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// A `VectorStore` that keeps vectors in memory and searches them exactly, so that code depending on a vector
+/// store can be tested without network access.
+///
+/// Requests are validated and fail with the same errors as an `Index` would. Clones of an `InMemoryVectorStore`
+/// share the same vectors.
+///
+/// ### Example
+/// ```
+/// use pinecone_sdk::models::{Metric, Namespace, Vector};
+/// use pinecone_sdk::pinecone::data::VectorStore;
+/// use pinecone_sdk::testing::InMemoryVectorStore;
+/// # use pinecone_sdk::utils::errors::PineconeError;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), PineconeError>{
+/// let store = InMemoryVectorStore::new(2, Metric::Cosine);
+///
+/// let vectors = [Vector {
+///     id: "id1".to_string(),
+///     values: vec![1.0, 2.0],
+///     ..Default::default()
+/// }];
+/// store.upsert(&vectors, &Namespace::default()).await?;
+///
+/// let response = store
+///     .query_by_value(vec![1.0, 2.0], None, 1, &Namespace::default(), None, None, None)
+///     .await?;
+/// assert_eq!(response.matches[0].id, "id1");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct InMemoryVectorStore {
+    engine: Arc<VectorEngine>,
+}
+
+impl InMemoryVectorStore {
+    /// Creates an empty vector store.
+    ///
+    /// ### Arguments
+    /// * `dimension: u32` - The dimension of the vectors of the store.
+    /// * `metric: Metric` - The distance metric that queries rank vectors with.
+    ///
+    /// ### Return
+    /// * `InMemoryVectorStore`
+    pub fn new(dimension: u32, metric: Metric) -> Self {
+        InMemoryVectorStore {
+            engine: Arc::new(VectorEngine::new(dimension, metric)),
+        }
+    }
+
+    /// Creates a vector store that shares the vectors of an engine, such as the one served by a `FakeDataPlane`.
+    pub(crate) fn from_engine(engine: Arc<VectorEngine>) -> Self {
+        InMemoryVectorStore { engine }
+    }
+}
+
+#[async_trait]
+impl VectorStore for InMemoryVectorStore {
+    async fn upsert(
+        &self,
+        vectors: &[Vector],
+        namespace: &Namespace,
+    ) -> Result<UpsertResponse, PineconeError> {
+        let request = protos::UpsertRequest {
+            vectors: vectors.to_vec(),
+            namespace: namespace.name.clone(),
+        };
+
+        Ok(self.engine.upsert(request)?)
+    }
+
+    async fn list(
+        &self,
+        namespace: &Namespace,
+        prefix: Option<&str>,
+        limit: Option<u32>,
+        pagination_token: Option<&str>,
+    ) -> Result<ListResponse, PineconeError> {
+        let request = protos::ListRequest {
+            namespace: namespace.name.clone(),
+            prefix: prefix.map(|s| s.to_string()),
+            limit,
+            pagination_token: pagination_token.map(|s| s.to_string()),
+        };
+
+        Ok(self.engine.list(request)?)
+    }
+
+    async fn describe_index_stats(
+        &self,
+        filter: Option<Metadata>,
+    ) -> Result<DescribeIndexStatsResponse, PineconeError> {
+        let request = protos::DescribeIndexStatsRequest { filter };
+
+        Ok(self.engine.describe_index_stats(request)?)
+    }
+
+    async fn update(
+        &self,
+        id: &str,
+        values: Vec<f32>,
+        sparse_values: Option<SparseValues>,
+        metadata: Option<Metadata>,
+        namespace: &Namespace,
+    ) -> Result<UpdateResponse, PineconeError> {
+        let request = protos::UpdateRequest {
+            id: id.to_string(),
+            values,
+            sparse_values,
+            set_metadata: metadata,
+            namespace: namespace.name.clone(),
+        };
+
+        Ok(self.engine.update(request)?)
+    }
+
+    async fn query_by_id(
+        &self,
+        id: &str,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError> {
+        #[allow(deprecated)]
+        let request = protos::QueryRequest {
+            id: id.to_string(),
+            top_k,
+            namespace: namespace.name.clone(),
+            filter,
+            include_values: include_values.unwrap_or(false),
+            include_metadata: include_metadata.unwrap_or(false),
+            queries: vec![],
+            vector: vec![],
+            sparse_vector: None,
+        };
+
+        Ok(self.engine.query(request)?)
+    }
+
+    async fn query_by_value(
+        &self,
+        vector: Vec<f32>,
+        sparse_vector: Option<SparseValues>,
+        top_k: u32,
+        namespace: &Namespace,
+        filter: Option<Metadata>,
+        include_values: Option<bool>,
+        include_metadata: Option<bool>,
+    ) -> Result<QueryResponse, PineconeError> {
+        #[allow(deprecated)]
+        let request = protos::QueryRequest {
+            id: "".to_string(),
+            top_k,
+            namespace: namespace.name.clone(),
+            filter,
+            include_values: include_values.unwrap_or(false),
+            include_metadata: include_metadata.unwrap_or(false),
+            queries: vec![],
+            vector,
+            sparse_vector,
+        };
+
+        Ok(self.engine.query(request)?)
+    }
+
+    async fn delete_by_id(&self, ids: &[&str], namespace: &Namespace) -> Result<(), PineconeError> {
+        let request = protos::DeleteRequest {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            delete_all: false,
+            namespace: namespace.name.clone(),
+            filter: None,
+        };
+
+        self.engine.delete(request)?;
+        Ok(())
+    }
+
+    async fn delete_all(&self, namespace: &Namespace) -> Result<(), PineconeError> {
+        let request = protos::DeleteRequest {
+            ids: vec![],
+            delete_all: true,
+            namespace: namespace.name.clone(),
+            filter: None,
+        };
+
+        self.engine.delete(request)?;
+        Ok(())
+    }
+
+    async fn delete_by_filter(
+        &self,
+        filter: Metadata,
+        namespace: &Namespace,
+    ) -> Result<(), PineconeError> {
+        let request = protos::DeleteRequest {
+            ids: vec![],
+            delete_all: false,
+            namespace: namespace.name.clone(),
+            filter: Some(filter),
+        };
+
+        self.engine.delete(request)?;
+        Ok(())
+    }
+
+    async fn fetch(
+        &self,
+        ids: &[&str],
+        namespace: &Namespace,
+    ) -> Result<FetchResponse, PineconeError> {
+        let request = protos::FetchRequest {
+            ids: ids.iter().map(|id| id.to_string()).collect(),
+            namespace: namespace.name.clone(),
+        };
+
+        Ok(self.engine.fetch(request)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{json_to_metadata, Filter};
+    use crate::pinecone::PineconeClientConfig;
+    use crate::testing::FakeDataPlane;
+    use serde_json::json;
+    use tokio;
+
+    // Domain logic that depends on a vector store: upserts documents, and returns the IDs of the comedies most
+    // similar to a query
+    async fn similar_comedies(store: &dyn VectorStore) -> Result<Vec<String>, PineconeError> {
+        let namespace = Namespace::from("movies");
+        let vectors: Vec<Vector> = [
+            ("movie1", [1.0, 0.0], "comedy"),
+            ("movie2", [0.9, 0.1], "drama"),
+            ("movie3", [0.5, 0.5], "comedy"),
+            ("movie4", [0.0, 1.0], "comedy"),
+        ]
+        .into_iter()
+        .map(|(id, values, genre)| Vector {
+            id: id.to_string(),
+            values: values.to_vec(),
+            sparse_values: None,
+            metadata: Some(json_to_metadata(&json!({ "genre": genre })).unwrap()),
+        })
+        .collect();
+        store.upsert(&vectors, &namespace).await?;
+        store.delete_by_id(&["movie4"], &namespace).await?;
+
+        let filter = Filter::eq("genre", "comedy").build()?;
+        let response = store
+            .query_by_value(
+                vec![1.0, 0.0],
+                None,
+                5,
+                &namespace,
+                Some(filter),
+                None,
+                None,
+            )
+            .await?;
+
+        Ok(response.matches.into_iter().map(|m| m.id).collect())
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_vector_store() {
+        let store = InMemoryVectorStore::new(2, Metric::Cosine);
+
+        let ids = similar_comedies(&store).await.unwrap();
+        assert_eq!(ids, ["movie1", "movie3"]);
+
+        let stats = store.describe_index_stats(None).await.unwrap();
+        assert_eq!(stats.total_vector_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_vector_store_errors() {
+        let store = InMemoryVectorStore::new(3, Metric::Cosine);
+
+        let error = similar_comedies(&store).await.unwrap_err();
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneInvalidArgumentError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_index_vector_store() {
+        let data_plane = FakeDataPlane::new(2, Metric::Cosine);
+        let server = data_plane.start().await.unwrap();
+        let pinecone = PineconeClientConfig {
+            api_key: Some("api-key".to_string()),
+            ..Default::default()
+        }
+        .client()
+        .unwrap();
+        let index = pinecone.index(&server.host()).await.unwrap();
+
+        let ids = similar_comedies(&index).await.unwrap();
+        assert_eq!(ids, ["movie1", "movie3"]);
+
+        // the vectors served by the fake data plane are those upserted through the index
+        let response = data_plane
+            .vector_store()
+            .fetch(&["movie1", "movie4"], &"movies".into())
+            .await
+            .unwrap();
+        assert_eq!(response.vectors.len(), 1);
+    }
+}
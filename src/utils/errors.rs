@@ -1,10 +1,16 @@
 use crate::openapi::apis::{Error as OpenApiError, ResponseContent};
+use crate::openapi::models::error_response_error::Code as ErrorCode;
+use crate::openapi::models::ErrorResponse;
 use anyhow::Error as AnyhowError;
 use reqwest::{self, StatusCode};
 use thiserror::Error;
 
 /// PineconeError is the error type for all Pinecone SDK errors.
+///
+/// New variants may be added in minor releases. Prefer the classification methods, such as `is_retryable()`,
+/// `is_not_found()` or `http_status()`, over matching on every variant.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum PineconeError {
     /// UnknownResponseError: Unknown response error.
     #[error("Unknown response error: status: {status}, message: {message}")]
@@ -120,6 +126,31 @@ pub enum PineconeError {
         message: String,
     },
 
+    /// InvalidFilterError: Provided metadata filter is not valid.
+    #[error("Invalid filter error: {message}")]
+    InvalidFilterError {
+        /// Error message.
+        message: String,
+    },
+
+    /// InvalidMetadataError: Provided value cannot be represented as vector metadata.
+    #[error("Invalid metadata error: {message}")]
+    InvalidMetadataError {
+        /// Error message.
+        message: String,
+    },
+
+    /// ValidationError: A vector or query is not valid for the index it is sent to.
+    #[error("Validation error: {field} of {}: {message}", id.as_deref().map_or("query".to_string(), |id| format!("vector \"{id}\"")))]
+    ValidationError {
+        /// ID of the offending vector, or `None` for a query by vector.
+        id: Option<String>,
+        /// Name of the offending field.
+        field: String,
+        /// Error message.
+        message: String,
+    },
+
     /// CollectionNotFoundError: Collection of given name does not exist
     #[error("Collection not found error: {source}")]
     CollectionNotFoundError {
@@ -155,6 +186,20 @@ pub enum PineconeError {
         source: WrappedResponseContent,
     },
 
+    /// RateLimitedError: Too many requests were sent, or a rate limit was exceeded.
+    #[error("Rate limited error: {source}")]
+    RateLimitedError {
+        /// Source error
+        source: WrappedResponseContent,
+    },
+
+    /// ServiceUnavailableError: The service is temporarily unavailable.
+    #[error("Service unavailable error: {source}")]
+    ServiceUnavailableError {
+        /// Source error
+        source: WrappedResponseContent,
+    },
+
     /// InternalServerError: Internal server error
     #[error("Internal server error: {source}")]
     InternalServerError {
@@ -163,13 +208,71 @@ pub enum PineconeError {
     },
 
     /// DataPlaneError: Failed to perform a data plane operation.
+    #[cfg(feature = "data")]
     #[error("Data plane error: {status}")]
     DataPlaneError {
         /// Error status
         status: tonic::Status,
     },
 
+    /// DataPlaneNotFoundError: The index, namespace or operation of a data plane request was not found.
+    #[cfg(feature = "data")]
+    #[error("Data plane not found error: {status}")]
+    DataPlaneNotFoundError {
+        /// Error status
+        status: tonic::Status,
+    },
+
+    /// DataPlaneInvalidArgumentError: The data plane request included invalid parameters.
+    #[cfg(feature = "data")]
+    #[error("Data plane invalid argument error: {status}")]
+    DataPlaneInvalidArgumentError {
+        /// Error status
+        status: tonic::Status,
+    },
+
+    /// DataPlaneUnauthenticatedError: The data plane request was not authenticated. Possibly caused by invalid API key
+    #[cfg(feature = "data")]
+    #[error("Data plane unauthenticated error: {status}")]
+    DataPlaneUnauthenticatedError {
+        /// Error status
+        status: tonic::Status,
+    },
+
+    /// DataPlanePermissionDeniedError: The API key is not allowed to perform the data plane operation.
+    #[cfg(feature = "data")]
+    #[error("Data plane permission denied error: {status}")]
+    DataPlanePermissionDeniedError {
+        /// Error status
+        status: tonic::Status,
+    },
+
+    /// DataPlaneResourceExhaustedError: The data plane request was rate limited, or exceeded a quota.
+    #[cfg(feature = "data")]
+    #[error("Data plane resource exhausted error: {status}")]
+    DataPlaneResourceExhaustedError {
+        /// Error status
+        status: tonic::Status,
+    },
+
+    /// DataPlaneUnavailableError: The index is temporarily unavailable.
+    #[cfg(feature = "data")]
+    #[error("Data plane unavailable error: {status}")]
+    DataPlaneUnavailableError {
+        /// Error status
+        status: tonic::Status,
+    },
+
+    /// DataPlaneDeadlineExceededError: The data plane request did not complete before its deadline.
+    #[cfg(feature = "data")]
+    #[error("Data plane deadline exceeded error: {status}")]
+    DataPlaneDeadlineExceededError {
+        /// Error status
+        status: tonic::Status,
+    },
+
     /// InferenceError: Failed to perform an inference operation.
+    #[cfg(feature = "data")]
     #[error("Inference error: {status}")]
     InferenceError {
         /// Error status
@@ -177,65 +280,423 @@ pub enum PineconeError {
     },
 }
 
-// Implement the conversion from OpenApiError to PineconeError for CreateIndexError.
+// Implement the conversion from OpenApiError to PineconeError, for requests to any endpoint.
 impl<T> From<OpenApiError<T>> for PineconeError {
     fn from(error: OpenApiError<T>) -> Self {
+        PineconeError::from_openapi(error, ControlPlaneEndpoint::Other)
+    }
+}
+
+impl PineconeError {
+    // Converts the error of a request to `endpoint`, which tells what kind of resource an error response is about
+    pub(crate) fn from_openapi<T>(error: OpenApiError<T>, endpoint: ControlPlaneEndpoint) -> Self {
         match error {
             OpenApiError::Reqwest(inner) => PineconeError::ReqwestError {
                 source: inner.into(),
             },
+            OpenApiError::ReqwestMiddleware(reqwest_middleware::Error::Reqwest(inner)) => {
+                PineconeError::ReqwestError {
+                    source: inner.into(),
+                }
+            }
+            OpenApiError::ReqwestMiddleware(reqwest_middleware::Error::Middleware(inner)) => {
+                PineconeError::ReqwestError { source: inner }
+            }
             OpenApiError::Serde(inner) => PineconeError::SerdeError {
                 source: inner.into(),
             },
             OpenApiError::Io(inner) => PineconeError::IoError {
                 message: inner.to_string(),
             },
-            OpenApiError::ResponseError(inner) => handle_response_error(inner.into()),
+            OpenApiError::ResponseError(inner) => handle_response_error(inner.into(), endpoint),
+        }
+    }
+}
+
+/// The control plane endpoint a request was sent to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "control"), allow(dead_code))]
+pub(crate) enum ControlPlaneEndpoint {
+    /// `POST /indexes`, and whether the index is created from a collection.
+    CreateIndex { from_collection: bool },
+    /// `PATCH /indexes/{name}`
+    ConfigureIndex,
+    /// `GET` or `DELETE /indexes/{name}`
+    Index,
+    /// `POST /collections`
+    CreateCollection,
+    /// `GET` or `DELETE /collections/{name}`
+    Collection,
+    /// Any other endpoint, such as listing indexes or embedding.
+    Other,
+}
+
+// Implement the conversion from a data plane gRPC status to PineconeError.
+// The status is kept as is, so that its message and metadata, such as the request ID, are preserved.
+#[cfg(feature = "data")]
+impl From<tonic::Status> for PineconeError {
+    fn from(status: tonic::Status) -> Self {
+        match status.code() {
+            tonic::Code::NotFound => PineconeError::DataPlaneNotFoundError { status },
+            tonic::Code::InvalidArgument => PineconeError::DataPlaneInvalidArgumentError { status },
+            tonic::Code::Unauthenticated => PineconeError::DataPlaneUnauthenticatedError { status },
+            tonic::Code::PermissionDenied => {
+                PineconeError::DataPlanePermissionDeniedError { status }
+            }
+            tonic::Code::ResourceExhausted => {
+                PineconeError::DataPlaneResourceExhaustedError { status }
+            }
+            tonic::Code::Unavailable => PineconeError::DataPlaneUnavailableError { status },
+            tonic::Code::DeadlineExceeded => {
+                PineconeError::DataPlaneDeadlineExceededError { status }
+            }
+            // the client cancels requests whose `grpc-timeout` expires before the server responds
+            tonic::Code::Cancelled if is_timeout_expired(&status) => {
+                PineconeError::DataPlaneDeadlineExceededError {
+                    status: tonic::Status::deadline_exceeded(status.message()),
+                }
+            }
+            _ => PineconeError::DataPlaneError { status },
         }
     }
 }
 
-// Helper function to handle response errors
-fn handle_response_error(source: WrappedResponseContent) -> PineconeError {
-    let status = source.status;
-    let message = source.content.clone();
+// Helper function to check whether a status was caused by the client-side timeout of the request expiring
+#[cfg(feature = "data")]
+fn is_timeout_expired(status: &tonic::Status) -> bool {
+    let mut source = std::error::Error::source(status);
+    while let Some(error) = source {
+        if error.is::<tonic::TimeoutExpired>() {
+            return true;
+        }
+        source = error.source();
+    }
+    false
+}
+
+/// TransientErrorKind: Classes of transient errors, for which a retried request may succeed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransientErrorKind {
+    /// The request was rate limited: HTTP 429 or gRPC `RESOURCE_EXHAUSTED`.
+    RateLimited,
+    /// The server failed to process the request: HTTP 500 or 502.
+    ServerError,
+    /// The service is temporarily unavailable: HTTP 503, gRPC `UNAVAILABLE`, or a failed connection.
+    Unavailable,
+    /// The request timed out: HTTP 504, gRPC `DEADLINE_EXCEEDED`, or a client-side timeout.
+    Timeout,
+}
+
+impl PineconeError {
+    /// Returns the class of transient error this error belongs to, or `None` if retrying the request is not expected to help.
+    pub fn transient_kind(&self) -> Option<TransientErrorKind> {
+        match self {
+            PineconeError::UnknownResponseError { status, .. } => http_transient_kind(*status),
+            PineconeError::InternalServerError { source }
+            | PineconeError::RateLimitedError { source }
+            | PineconeError::ServiceUnavailableError { source } => {
+                http_transient_kind(source.status).or(match source.code() {
+                    Some(ErrorCode::ResourceExhausted) => Some(TransientErrorKind::RateLimited),
+                    Some(ErrorCode::Unavailable) => Some(TransientErrorKind::Unavailable),
+                    _ => None,
+                })
+            }
+            PineconeError::ReqwestError { source } => {
+                let error = source.downcast_ref::<reqwest::Error>()?;
+                if error.is_timeout() {
+                    Some(TransientErrorKind::Timeout)
+                } else if error.is_connect() {
+                    Some(TransientErrorKind::Unavailable)
+                } else {
+                    error.status().and_then(http_transient_kind)
+                }
+            }
+            #[cfg(feature = "data")]
+            _ => self.grpc_code().and_then(grpc_transient_kind),
+            #[cfg(not(feature = "data"))]
+            _ => None,
+        }
+    }
+
+    /// Returns whether retrying the request that failed with this error may succeed.
+    pub fn is_retryable(&self) -> bool {
+        self.transient_kind().is_some()
+    }
+
+    /// Returns whether the error reports that a resource, such as an index, collection or namespace, was not found.
+    pub fn is_not_found(&self) -> bool {
+        #[cfg(feature = "data")]
+        if self.grpc_code() == Some(tonic::Code::NotFound) {
+            return true;
+        }
+
+        matches!(
+            self,
+            PineconeError::IndexNotFoundError { .. }
+                | PineconeError::CollectionNotFoundError { .. }
+        ) || self.http_status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Returns whether the error was caused by the request or the client configuration, so that sending
+    /// the same request again is expected to fail in the same way. Rate limited requests are not client errors.
+    pub fn is_client_error(&self) -> bool {
+        match self {
+            PineconeError::APIKeyMissingError { .. }
+            | PineconeError::InvalidHeadersError { .. }
+            | PineconeError::InvalidConfigurationError { .. }
+            | PineconeError::InvalidFilterError { .. }
+            | PineconeError::InvalidMetadataError { .. }
+            | PineconeError::ValidationError { .. } => true,
+            _ => {
+                if let Some(status) = self.http_status() {
+                    return status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS;
+                }
+
+                #[cfg(feature = "data")]
+                if let Some(code) = self.grpc_code() {
+                    return matches!(
+                        code,
+                        tonic::Code::InvalidArgument
+                            | tonic::Code::NotFound
+                            | tonic::Code::AlreadyExists
+                            | tonic::Code::PermissionDenied
+                            | tonic::Code::Unauthenticated
+                            | tonic::Code::FailedPrecondition
+                            | tonic::Code::OutOfRange
+                    );
+                }
+
+                false
+            }
+        }
+    }
+
+    /// Returns the HTTP status of the response that caused the error, for control plane and inference errors.
+    pub fn http_status(&self) -> Option<StatusCode> {
+        match self {
+            PineconeError::UnknownResponseError { status, .. } => Some(*status),
+            PineconeError::ReqwestError { source } => {
+                source.downcast_ref::<reqwest::Error>()?.status()
+            }
+            _ => self.response_content().map(|source| source.status),
+        }
+    }
 
+    /// Returns the gRPC status code of the response that caused the error, for data plane errors.
+    #[cfg(feature = "data")]
+    pub fn grpc_code(&self) -> Option<tonic::Code> {
+        self.grpc_status().map(tonic::Status::code)
+    }
+
+    /// Returns the ID the server assigned to the request that caused the error, if the response included one.
+    ///
+    /// The request ID is read from the `x-pinecone-request-id` or `x-request-id` metadata of gRPC responses.
+    /// Control plane error responses do not expose their headers, so `None` is returned for them.
+    pub fn request_id(&self) -> Option<&str> {
+        #[cfg(feature = "data")]
+        if let Some(status) = self.grpc_status() {
+            let metadata = status.metadata();
+            return ["x-pinecone-request-id", "x-request-id"]
+                .iter()
+                .find_map(|key| metadata.get(*key)?.to_str().ok());
+        }
+
+        None
+    }
+
+    /// Returns the parsed response of a control plane error, including its error code and details.
+    pub fn response_content(&self) -> Option<&WrappedResponseContent> {
+        match self {
+            PineconeError::ActionForbiddenError { source }
+            | PineconeError::BadRequestError { source }
+            | PineconeError::UnauthorizedError { source }
+            | PineconeError::PodQuotaExceededError { source }
+            | PineconeError::CollectionsQuotaExceededError { source }
+            | PineconeError::InvalidCloudError { source }
+            | PineconeError::InvalidRegionError { source }
+            | PineconeError::CollectionNotFoundError { source }
+            | PineconeError::IndexNotFoundError { source }
+            | PineconeError::ResourceAlreadyExistsError { source }
+            | PineconeError::UnprocessableEntityError { source }
+            | PineconeError::PendingCollectionError { source }
+            | PineconeError::RateLimitedError { source }
+            | PineconeError::ServiceUnavailableError { source }
+            | PineconeError::InternalServerError { source } => Some(source),
+            _ => None,
+        }
+    }
+
+    // Returns the gRPC status of a data plane or inference error
+    #[cfg(feature = "data")]
+    fn grpc_status(&self) -> Option<&tonic::Status> {
+        match self {
+            PineconeError::InferenceError { status } => Some(status),
+            _ => self.data_plane_status(),
+        }
+    }
+
+    // Returns the gRPC status of a data plane error
+    #[cfg(feature = "data")]
+    pub(crate) fn data_plane_status(&self) -> Option<&tonic::Status> {
+        match self {
+            PineconeError::DataPlaneError { status }
+            | PineconeError::DataPlaneNotFoundError { status }
+            | PineconeError::DataPlaneInvalidArgumentError { status }
+            | PineconeError::DataPlaneUnauthenticatedError { status }
+            | PineconeError::DataPlanePermissionDeniedError { status }
+            | PineconeError::DataPlaneResourceExhaustedError { status }
+            | PineconeError::DataPlaneUnavailableError { status }
+            | PineconeError::DataPlaneDeadlineExceededError { status } => Some(status),
+            _ => None,
+        }
+    }
+}
+
+// Helper function to classify HTTP status codes as transient errors
+fn http_transient_kind(status: StatusCode) -> Option<TransientErrorKind> {
     match status {
+        StatusCode::TOO_MANY_REQUESTS => Some(TransientErrorKind::RateLimited),
+        StatusCode::INTERNAL_SERVER_ERROR | StatusCode::BAD_GATEWAY => {
+            Some(TransientErrorKind::ServerError)
+        }
+        StatusCode::SERVICE_UNAVAILABLE => Some(TransientErrorKind::Unavailable),
+        StatusCode::GATEWAY_TIMEOUT => Some(TransientErrorKind::Timeout),
+        _ => None,
+    }
+}
+
+// Helper function to classify gRPC status codes as transient errors
+#[cfg(feature = "data")]
+fn grpc_transient_kind(code: tonic::Code) -> Option<TransientErrorKind> {
+    match code {
+        tonic::Code::ResourceExhausted => Some(TransientErrorKind::RateLimited),
+        tonic::Code::Unavailable => Some(TransientErrorKind::Unavailable),
+        tonic::Code::DeadlineExceeded => Some(TransientErrorKind::Timeout),
+        _ => None,
+    }
+}
+
+// Helper function to handle response errors.
+// The HTTP status selects the variant. The error code of the response body is used when the status alone is
+// ambiguous, and the endpoint to tell which kind of resource was not found or over quota.
+fn handle_response_error(
+    source: WrappedResponseContent,
+    endpoint: ControlPlaneEndpoint,
+) -> PineconeError {
+    match source.status {
         StatusCode::BAD_REQUEST => PineconeError::BadRequestError { source },
         StatusCode::UNAUTHORIZED => PineconeError::UnauthorizedError { source },
-        StatusCode::FORBIDDEN => parse_forbidden_error(source, message),
-        StatusCode::NOT_FOUND => parse_not_found_error(source, message),
+        StatusCode::FORBIDDEN => parse_forbidden_error(source, endpoint),
+        StatusCode::NOT_FOUND => parse_not_found_error(source, endpoint),
         StatusCode::CONFLICT => PineconeError::ResourceAlreadyExistsError { source },
         StatusCode::PRECONDITION_FAILED => PineconeError::PendingCollectionError { source },
         StatusCode::UNPROCESSABLE_ENTITY => PineconeError::UnprocessableEntityError { source },
+        StatusCode::TOO_MANY_REQUESTS => PineconeError::RateLimitedError { source },
         StatusCode::INTERNAL_SERVER_ERROR => PineconeError::InternalServerError { source },
-        _ => PineconeError::UnknownResponseError { status, message },
+        StatusCode::SERVICE_UNAVAILABLE => PineconeError::ServiceUnavailableError { source },
+        _ => parse_error_code(source, endpoint),
     }
 }
 
-fn parse_not_found_error(source: WrappedResponseContent, message: String) -> PineconeError {
-    if message.contains("Index") {
-        PineconeError::IndexNotFoundError { source }
-    } else if message.contains("Collection") {
-        PineconeError::CollectionNotFoundError { source }
-    } else if message.contains("region") {
-        PineconeError::InvalidRegionError { source }
-    } else if message.contains("cloud") {
+// Maps the error code of a response with an unexpected HTTP status
+fn parse_error_code(
+    source: WrappedResponseContent,
+    endpoint: ControlPlaneEndpoint,
+) -> PineconeError {
+    match source.code() {
+        Some(ErrorCode::InvalidArgument | ErrorCode::OutOfRange) => {
+            PineconeError::BadRequestError { source }
+        }
+        Some(ErrorCode::Unauthenticated) => PineconeError::UnauthorizedError { source },
+        Some(ErrorCode::Forbidden | ErrorCode::PermissionDenied | ErrorCode::QuotaExceeded) => {
+            parse_forbidden_error(source, endpoint)
+        }
+        Some(ErrorCode::NotFound) => parse_not_found_error(source, endpoint),
+        Some(ErrorCode::AlreadyExists) => PineconeError::ResourceAlreadyExistsError { source },
+        Some(ErrorCode::FailedPrecondition) => PineconeError::PendingCollectionError { source },
+        Some(ErrorCode::UnprocessableEntity) => PineconeError::UnprocessableEntityError { source },
+        Some(ErrorCode::ResourceExhausted) => PineconeError::RateLimitedError { source },
+        Some(ErrorCode::Unavailable) => PineconeError::ServiceUnavailableError { source },
+        Some(ErrorCode::Internal | ErrorCode::DataLoss) => {
+            PineconeError::InternalServerError { source }
+        }
+        _ => PineconeError::UnknownResponseError {
+            status: source.status,
+            message: source.content,
+        },
+    }
+}
+
+// Creating an index fails with NOT_FOUND for its source collection, or for a cloud and region, or environment, that
+// are not available. Endpoints that are not about a single index or collection, such as listing indexes, are not
+// told apart by the variant.
+fn parse_not_found_error(
+    source: WrappedResponseContent,
+    endpoint: ControlPlaneEndpoint,
+) -> PineconeError {
+    match endpoint {
+        ControlPlaneEndpoint::CreateIndex {
+            from_collection: true,
+        }
+        | ControlPlaneEndpoint::Collection => PineconeError::CollectionNotFoundError { source },
+        ControlPlaneEndpoint::CreateIndex {
+            from_collection: false,
+        } => parse_invalid_location_error(source),
+        ControlPlaneEndpoint::ConfigureIndex
+        | ControlPlaneEndpoint::Index
+        | ControlPlaneEndpoint::CreateCollection => PineconeError::IndexNotFoundError { source },
+        ControlPlaneEndpoint::Other => PineconeError::UnknownResponseError {
+            status: source.status,
+            message: source.content,
+        },
+    }
+}
+
+// The error code of a cloud or region that is not available is NOT_FOUND for both, so the field named by the
+// details, or else the message, tells them apart. The region is reported when neither names the cloud alone.
+fn parse_invalid_location_error(source: WrappedResponseContent) -> PineconeError {
+    let field = source
+        .details()
+        .and_then(|details| details.get("field")?.as_str().map(str::to_string));
+    let invalid_cloud = match field {
+        Some(field) => field.ends_with("cloud"),
+        None => source
+            .message()
+            .is_some_and(|message| message.contains("cloud") && !message.contains("region")),
+    };
+
+    if invalid_cloud {
         PineconeError::InvalidCloudError { source }
     } else {
-        PineconeError::InternalServerError { source }
+        PineconeError::InvalidRegionError { source }
     }
 }
 
-fn parse_forbidden_error(source: WrappedResponseContent, message: String) -> PineconeError {
-    if message.contains("Deletion protection") {
-        PineconeError::ActionForbiddenError { source }
-    } else if message.contains("index") {
-        PineconeError::PodQuotaExceededError { source }
-    } else if message.contains("Collection") {
-        PineconeError::CollectionsQuotaExceededError { source }
-    } else {
-        PineconeError::InternalServerError { source }
+// The control plane reports projects over quota with QUOTA_EXCEEDED, or FORBIDDEN for the endpoints that create or
+// scale resources. Other forbidden requests, such as deleting an index with deletion protection, are actions that
+// are not allowed.
+fn parse_forbidden_error(
+    source: WrappedResponseContent,
+    endpoint: ControlPlaneEndpoint,
+) -> PineconeError {
+    let quota_exceeded = match source.code() {
+        Some(ErrorCode::QuotaExceeded) => true,
+        Some(ErrorCode::Forbidden) | None => matches!(
+            endpoint,
+            ControlPlaneEndpoint::CreateIndex { .. }
+                | ControlPlaneEndpoint::ConfigureIndex
+                | ControlPlaneEndpoint::CreateCollection
+        ),
+        _ => false,
+    };
+
+    match endpoint {
+        _ if !quota_exceeded => PineconeError::ActionForbiddenError { source },
+        ControlPlaneEndpoint::CreateCollection => {
+            PineconeError::CollectionsQuotaExceededError { source }
+        }
+        _ => PineconeError::PodQuotaExceededError { source },
     }
 }
 
@@ -248,12 +709,42 @@ pub struct WrappedResponseContent {
     pub content: String,
 }
 
+impl WrappedResponseContent {
+    /// Creates a WrappedResponseContent from the status and the body of a response.
+    pub fn new(status: reqwest::StatusCode, content: String) -> Self {
+        WrappedResponseContent { status, content }
+    }
+
+    /// Returns the error code of the response, if its body is a Pinecone error response.
+    pub fn code(&self) -> Option<ErrorCode> {
+        self.error_response().map(|response| response.error.code)
+    }
+
+    /// Returns the error message of the response, if its body is a Pinecone error response.
+    pub fn message(&self) -> Option<String> {
+        if let Some(response) = self.error_response() {
+            return Some(response.error.message);
+        }
+
+        // some endpoints respond with a plain `{"error": "message"}` body
+        serde_json::from_str::<serde_json::Value>(&self.content)
+            .ok()
+            .and_then(|body| body.get("error")?.as_str().map(str::to_string))
+    }
+
+    /// Returns the error details of the response, if its body is a Pinecone error response that has details.
+    pub fn details(&self) -> Option<serde_json::Value> {
+        self.error_response()?.error.details
+    }
+
+    fn error_response(&self) -> Option<ErrorResponse> {
+        serde_json::from_str(&self.content).ok()
+    }
+}
+
 impl<T> From<ResponseContent<T>> for WrappedResponseContent {
     fn from(rc: ResponseContent<T>) -> Self {
-        WrappedResponseContent {
-            status: rc.status,
-            content: rc.content,
-        }
+        WrappedResponseContent::new(rc.status, rc.content)
     }
 }
 
@@ -271,7 +762,7 @@ impl std::fmt::Display for WrappedResponseContent {
 
 #[cfg(test)]
 mod tests {
-    use super::PineconeError;
+    use super::*;
     use tokio;
 
     fn assert_send_sync<T: Send + Sync>() {}
@@ -280,4 +771,440 @@ mod tests {
     async fn test_pinecone_error_is_send_sync() {
         assert_send_sync::<PineconeError>();
     }
+
+    #[tokio::test]
+    async fn test_transient_kind_http() {
+        let error = |status: StatusCode| PineconeError::UnknownResponseError {
+            status,
+            message: "".to_string(),
+        };
+
+        assert_eq!(
+            error(StatusCode::TOO_MANY_REQUESTS).transient_kind(),
+            Some(TransientErrorKind::RateLimited)
+        );
+        assert_eq!(
+            error(StatusCode::SERVICE_UNAVAILABLE).transient_kind(),
+            Some(TransientErrorKind::Unavailable)
+        );
+        assert_eq!(error(StatusCode::IM_A_TEAPOT).transient_kind(), None);
+
+        let error = PineconeError::InternalServerError {
+            source: WrappedResponseContent::new(StatusCode::INTERNAL_SERVER_ERROR, "".to_string()),
+        };
+        assert_eq!(
+            error.transient_kind(),
+            Some(TransientErrorKind::ServerError)
+        );
+    }
+
+    fn response_error(status: u16, body: serde_json::Value) -> PineconeError {
+        endpoint_error(ControlPlaneEndpoint::Other, status, body)
+    }
+
+    fn endpoint_error(
+        endpoint: ControlPlaneEndpoint,
+        status: u16,
+        body: serde_json::Value,
+    ) -> PineconeError {
+        handle_response_error(
+            WrappedResponseContent::new(StatusCode::from_u16(status).unwrap(), body.to_string()),
+            endpoint,
+        )
+    }
+
+    fn error_body(code: &str, message: &str) -> serde_json::Value {
+        serde_json::json!({
+            "status": 0,
+            "error": { "code": code, "message": message }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_wrapped_response_content_parses_body() {
+        let body = serde_json::json!({
+            "status": 400,
+            "error": {
+                "code": "INVALID_ARGUMENT",
+                "message": "Invalid dimension",
+                "details": { "field": "dimension" }
+            }
+        });
+        let source = WrappedResponseContent::new(StatusCode::BAD_REQUEST, body.to_string());
+
+        assert_eq!(source.code(), Some(ErrorCode::InvalidArgument));
+        assert_eq!(source.message().as_deref(), Some("Invalid dimension"));
+        assert_eq!(
+            source.details(),
+            Some(serde_json::json!({ "field": "dimension" }))
+        );
+
+        let source = WrappedResponseContent::new(StatusCode::BAD_REQUEST, "not json".to_string());
+        assert_eq!(source.code(), None);
+        assert_eq!(source.message(), None);
+        assert_eq!(source.details(), None);
+        assert_eq!(source.content, "not json");
+
+        let body = serde_json::json!({ "error": "Collection name not found" });
+        let source = WrappedResponseContent::new(StatusCode::NOT_FOUND, body.to_string());
+        assert_eq!(source.code(), None);
+        assert_eq!(
+            source.message().as_deref(),
+            Some("Collection name not found")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_error_not_found() {
+        let not_found =
+            |name: &str| error_body("NOT_FOUND", &format!("Index \"{name}\" not found"));
+
+        // index names that mention other resources are still indexes
+        for name in ["my-cloud-index", "us-east-region", "collection-backup"] {
+            assert!(matches!(
+                endpoint_error(ControlPlaneEndpoint::Index, 404, not_found(name)),
+                PineconeError::IndexNotFoundError { .. }
+            ));
+            assert!(matches!(
+                endpoint_error(ControlPlaneEndpoint::ConfigureIndex, 404, not_found(name)),
+                PineconeError::IndexNotFoundError { .. }
+            ));
+            assert!(matches!(
+                endpoint_error(ControlPlaneEndpoint::CreateCollection, 404, not_found(name)),
+                PineconeError::IndexNotFoundError { .. }
+            ));
+        }
+
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::Collection,
+                404,
+                error_body("NOT_FOUND", "Collection \"index-cloud-region\" not found")
+            ),
+            PineconeError::CollectionNotFoundError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::CreateIndex {
+                    from_collection: true
+                },
+                404,
+                error_body("NOT_FOUND", "Collection \"region-backup\" not found")
+            ),
+            PineconeError::CollectionNotFoundError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::CreateIndex {
+                    from_collection: false
+                },
+                404,
+                error_body("NOT_FOUND", "Resource cloud: aws region: abc not found.")
+            ),
+            PineconeError::InvalidRegionError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::CreateIndex {
+                    from_collection: false
+                },
+                404,
+                error_body("NOT_FOUND", "Resource cloud: abc not found.")
+            ),
+            PineconeError::InvalidCloudError { .. }
+        ));
+
+        // the field named by the details takes precedence over the message
+        let location_not_found = |field: &str| {
+            serde_json::json!({
+                "status": 404,
+                "error": {
+                    "code": "NOT_FOUND",
+                    "message": "Resource cloud: aws region: abc not found.",
+                    "details": { "field": field }
+                }
+            })
+        };
+        let create_index = ControlPlaneEndpoint::CreateIndex {
+            from_collection: false,
+        };
+        assert!(matches!(
+            endpoint_error(
+                create_index,
+                404,
+                location_not_found("spec.serverless.cloud")
+            ),
+            PineconeError::InvalidCloudError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                create_index,
+                404,
+                location_not_found("spec.serverless.region")
+            ),
+            PineconeError::InvalidRegionError { .. }
+        ));
+
+        // endpoints that are not about an index or collection are not reported as such
+        let error = response_error(404, error_body("NOT_FOUND", "Model \"index\" not found"));
+        assert!(matches!(error, PineconeError::UnknownResponseError { .. }));
+        assert!(error.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_response_error_forbidden() {
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::Index,
+                403,
+                error_body(
+                    "FORBIDDEN",
+                    "Deletion protection is enabled for index \"collection-quota\"."
+                )
+            ),
+            PineconeError::ActionForbiddenError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::Index,
+                403,
+                error_body("PERMISSION_DENIED", "Index \"pod-quota\" is read only.")
+            ),
+            PineconeError::ActionForbiddenError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::CreateIndex {
+                    from_collection: false
+                },
+                403,
+                error_body("QUOTA_EXCEEDED", "Too many indexes.")
+            ),
+            PineconeError::PodQuotaExceededError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::ConfigureIndex,
+                403,
+                error_body("FORBIDDEN", "Increase your quota to scale indexes.")
+            ),
+            PineconeError::PodQuotaExceededError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::CreateCollection,
+                403,
+                error_body("FORBIDDEN", "Too many resources.")
+            ),
+            PineconeError::CollectionsQuotaExceededError { .. }
+        ));
+        assert!(matches!(
+            endpoint_error(
+                ControlPlaneEndpoint::CreateIndex {
+                    from_collection: true
+                },
+                403,
+                error_body("PERMISSION_DENIED", "Collection \"quota\" is not shared.")
+            ),
+            PineconeError::ActionForbiddenError { .. }
+        ));
+        assert!(matches!(
+            response_error(403, error_body("QUOTA_EXCEEDED", "Too many requests.")),
+            PineconeError::PodQuotaExceededError { .. }
+        ));
+        assert!(matches!(
+            response_error(403, error_body("FORBIDDEN", "Collection exceeds quota.")),
+            PineconeError::ActionForbiddenError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_response_error_rate_limited_and_unavailable() {
+        let error = response_error(429, error_body("RESOURCE_EXHAUSTED", "Too many requests."));
+        assert!(matches!(error, PineconeError::RateLimitedError { .. }));
+        assert_eq!(
+            error.transient_kind(),
+            Some(TransientErrorKind::RateLimited)
+        );
+
+        let error = response_error(503, serde_json::json!("unavailable"));
+        assert!(matches!(
+            error,
+            PineconeError::ServiceUnavailableError { .. }
+        ));
+        assert_eq!(
+            error.transient_kind(),
+            Some(TransientErrorKind::Unavailable)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_error_unexpected_status() {
+        assert!(matches!(
+            response_error(418, error_body("UNAVAILABLE", "Try again later.")),
+            PineconeError::ServiceUnavailableError { .. }
+        ));
+        assert!(matches!(
+            response_error(418, serde_json::json!("teapot")),
+            PineconeError::UnknownResponseError { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_classify_control_plane_error() {
+        let error = endpoint_error(
+            ControlPlaneEndpoint::Index,
+            404,
+            error_body("NOT_FOUND", "Index index-name not found."),
+        );
+        assert!(error.is_not_found());
+        assert!(error.is_client_error());
+        assert!(!error.is_retryable());
+        assert_eq!(error.http_status(), Some(StatusCode::NOT_FOUND));
+        #[cfg(feature = "data")]
+        assert_eq!(error.grpc_code(), None);
+        assert_eq!(error.request_id(), None);
+        assert_eq!(
+            error.response_content().and_then(|source| source.code()),
+            Some(ErrorCode::NotFound)
+        );
+
+        let error = response_error(429, error_body("RESOURCE_EXHAUSTED", "Too many requests."));
+        assert!(!error.is_client_error());
+        assert!(error.is_retryable());
+
+        let error = response_error(500, serde_json::json!("internal"));
+        assert!(!error.is_not_found());
+        assert!(!error.is_client_error());
+        assert!(error.is_retryable());
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn test_classify_data_plane_error() {
+        let mut status = tonic::Status::not_found("Namespace not found");
+        status
+            .metadata_mut()
+            .insert("x-pinecone-request-id", "request-id".parse().unwrap());
+        let error = PineconeError::from(status);
+
+        assert!(error.is_not_found());
+        assert!(error.is_client_error());
+        assert!(!error.is_retryable());
+        assert_eq!(error.http_status(), None);
+        assert_eq!(error.grpc_code(), Some(tonic::Code::NotFound));
+        assert_eq!(error.request_id(), Some("request-id"));
+
+        let error = PineconeError::from(tonic::Status::unavailable(""));
+        assert!(!error.is_client_error());
+        assert!(error.is_retryable());
+        assert_eq!(error.request_id(), None);
+    }
+
+    #[tokio::test]
+    async fn test_classify_client_side_error() {
+        let error = PineconeError::InvalidFilterError {
+            message: "".to_string(),
+        };
+        assert!(error.is_client_error());
+        assert!(!error.is_retryable());
+        assert_eq!(error.http_status(), None);
+        #[cfg(feature = "data")]
+        assert_eq!(error.grpc_code(), None);
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn test_transient_kind_grpc() {
+        let error = PineconeError::from;
+
+        assert_eq!(
+            error(tonic::Status::resource_exhausted("")).transient_kind(),
+            Some(TransientErrorKind::RateLimited)
+        );
+        assert_eq!(
+            error(tonic::Status::unavailable("")).transient_kind(),
+            Some(TransientErrorKind::Unavailable)
+        );
+        assert_eq!(
+            error(tonic::Status::deadline_exceeded("")).transient_kind(),
+            Some(TransientErrorKind::Timeout)
+        );
+        assert_eq!(error(tonic::Status::not_found("")).transient_kind(), None);
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn test_data_plane_error_from_status() {
+        let cases = [
+            (tonic::Code::NotFound, "DataPlaneNotFoundError"),
+            (
+                tonic::Code::InvalidArgument,
+                "DataPlaneInvalidArgumentError",
+            ),
+            (
+                tonic::Code::Unauthenticated,
+                "DataPlaneUnauthenticatedError",
+            ),
+            (
+                tonic::Code::PermissionDenied,
+                "DataPlanePermissionDeniedError",
+            ),
+            (
+                tonic::Code::ResourceExhausted,
+                "DataPlaneResourceExhaustedError",
+            ),
+            (tonic::Code::Unavailable, "DataPlaneUnavailableError"),
+            (
+                tonic::Code::DeadlineExceeded,
+                "DataPlaneDeadlineExceededError",
+            ),
+            (tonic::Code::Internal, "DataPlaneError"),
+        ];
+
+        for (code, variant) in cases {
+            let error = PineconeError::from(tonic::Status::new(code, "message"));
+            assert!(format!("{:?}", error).starts_with(variant));
+            assert_eq!(error.data_plane_status().unwrap().code(), code);
+        }
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn test_data_plane_timeout_expired() {
+        // the status is built by tonic from the error of the expired timeout, which is kept as its source
+        let status = tonic::Status::from_error(Box::new(tonic::TimeoutExpired(())));
+        assert_eq!(status.code(), tonic::Code::Cancelled);
+        let error = PineconeError::from(status);
+        assert!(matches!(
+            error,
+            PineconeError::DataPlaneDeadlineExceededError { .. }
+        ));
+        assert_eq!(error.grpc_code(), Some(tonic::Code::DeadlineExceeded));
+
+        // a status with the same message but without the timeout as its source is not a timeout
+        let status = tonic::Status::cancelled(tonic::TimeoutExpired(()).to_string());
+        let error = PineconeError::from(status);
+        assert!(matches!(error, PineconeError::DataPlaneError { .. }));
+
+        let error = PineconeError::from(tonic::Status::cancelled("Cancelled by the server"));
+        assert!(matches!(error, PineconeError::DataPlaneError { .. }));
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn test_data_plane_error_preserves_status() {
+        let mut status = tonic::Status::not_found("Namespace not found");
+        status
+            .metadata_mut()
+            .insert("x-request-id", "request-id".parse().unwrap());
+
+        match PineconeError::from(status) {
+            PineconeError::DataPlaneNotFoundError { status } => {
+                assert_eq!(status.message(), "Namespace not found");
+                assert_eq!(status.metadata().get("x-request-id").unwrap(), "request-id");
+            }
+            error => panic!("Expected DataPlaneNotFoundError, got {:?}", error),
+        }
+    }
 }
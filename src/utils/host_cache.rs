@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// A cache of index hosts keyed by index name, shared between clones of a `PineconeClient`.
+#[derive(Debug, Clone)]
+pub(crate) struct IndexHostCache {
+    ttl: Duration,
+    entries: Arc<RwLock<HashMap<String, CachedHost>>>,
+}
+
+#[derive(Debug)]
+struct CachedHost {
+    host: String,
+    /// `None` if the TTL is too long to be represented, such as `Duration::MAX`, and the entry never expires.
+    expires_at: Option<Instant>,
+}
+
+impl IndexHostCache {
+    /// Creates an empty cache whose entries expire after `ttl`.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        IndexHostCache {
+            ttl,
+            entries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the cached host of the index, if it is present and has not expired.
+    pub(crate) fn get(&self, name: &str) -> Option<String> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(name)
+            .filter(|entry| {
+                entry
+                    .expires_at
+                    .map_or(true, |expires_at| expires_at > Instant::now())
+            })
+            .map(|entry| entry.host.clone())
+    }
+
+    /// Caches the host of the index.
+    pub(crate) fn insert(&self, name: &str, host: &str) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        entries.insert(
+            name.to_string(),
+            CachedHost {
+                host: host.to_string(),
+                expires_at: Instant::now().checked_add(self.ttl),
+            },
+        );
+    }
+
+    /// Removes the host of the index from the cache.
+    pub(crate) fn invalidate(&self, name: &str) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        entries.remove(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio;
+
+    #[tokio::test]
+    async fn test_host_cache_get() {
+        let cache = IndexHostCache::new(Duration::from_secs(60));
+        assert_eq!(cache.get("index-name"), None);
+
+        cache.insert("index-name", "index-host");
+        assert_eq!(cache.get("index-name"), Some("index-host".to_string()));
+        assert_eq!(cache.get("other-index-name"), None);
+    }
+
+    #[tokio::test]
+    async fn test_host_cache_shared_between_clones() {
+        let cache = IndexHostCache::new(Duration::from_secs(60));
+        let clone = cache.clone();
+
+        cache.insert("index-name", "index-host");
+        assert_eq!(clone.get("index-name"), Some("index-host".to_string()));
+
+        clone.invalidate("index-name");
+        assert_eq!(cache.get("index-name"), None);
+    }
+
+    #[tokio::test]
+    async fn test_host_cache_expired() {
+        let cache = IndexHostCache::new(Duration::ZERO);
+
+        cache.insert("index-name", "index-host");
+        assert_eq!(cache.get("index-name"), None);
+    }
+
+    #[tokio::test]
+    async fn test_host_cache_never_expires() {
+        let cache = IndexHostCache::new(Duration::MAX);
+
+        cache.insert("index-name", "index-host");
+        assert_eq!(cache.get("index-name"), Some("index-host".to_string()));
+    }
+}
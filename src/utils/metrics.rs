@@ -0,0 +1,366 @@
+use crate::utils::errors::PineconeError;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// An observer of the operations sent by the client, such as to aggregate latency, errors and usage.
+///
+/// The observer is registered with `PineconeClientConfig::metrics_observer`, and is invoked once per operation,
+/// after it completes, including retries. Data plane operations rejected by the client before a request is sent,
+/// such as by vector validation or an invalid query, are reported as client errors with a zero duration.
+pub trait MetricsObserver: std::fmt::Debug + Send + Sync {
+    /// Invoked after an operation completes.
+    fn observe(&self, event: &OperationEvent<'_>);
+}
+
+/// OperationEvent : A completed operation reported to a `MetricsObserver`.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct OperationEvent<'a> {
+    /// The name of the operation, such as `describe_index`, `upsert` or `embed`
+    pub operation: &'static str,
+    /// The host of the index, for data plane operations
+    pub index_host: Option<&'a str>,
+    /// The duration of the operation, including retries
+    pub duration: Duration,
+    /// Whether the operation succeeded, or how it failed
+    pub outcome: OperationOutcome,
+    /// The error the operation failed with, if any
+    pub error: Option<&'a PineconeError>,
+    /// The number of vectors upserted, fetched, listed or matched by the operation
+    pub vector_count: Option<u64>,
+    /// The read units consumed by the operation
+    pub read_units: Option<u64>,
+    /// The number of tokens embedded by the operation
+    pub total_tokens: Option<u64>,
+}
+
+/// OperationOutcome : Whether an operation succeeded, or the class of the error it failed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OperationOutcome {
+    /// The operation succeeded
+    Success,
+    /// The request or client configuration is invalid, and would fail again if retried
+    ClientError,
+    /// The operation failed with a transient error, and may succeed if retried
+    TransientError,
+    /// The operation failed with any other error, such as an internal server error
+    ServerError,
+}
+
+impl OperationOutcome {
+    /// Classifies the result of an operation.
+    pub fn of<T>(result: &Result<T, PineconeError>) -> Self {
+        match result {
+            Ok(_) => OperationOutcome::Success,
+            Err(e) => Self::of_error(e),
+        }
+    }
+
+    // Classifies the error of a failed operation
+    fn of_error(error: &PineconeError) -> Self {
+        if error.is_retryable() {
+            OperationOutcome::TransientError
+        } else if error.is_client_error() {
+            OperationOutcome::ClientError
+        } else {
+            OperationOutcome::ServerError
+        }
+    }
+
+    /// Returns the outcome as a snake case string, such as `client_error`, suitable as a metric label.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationOutcome::Success => "success",
+            OperationOutcome::ClientError => "client_error",
+            OperationOutcome::TransientError => "transient_error",
+            OperationOutcome::ServerError => "server_error",
+        }
+    }
+}
+
+/// The vector counts and usage reported by the response of an operation.
+pub(crate) trait OperationUsage {
+    fn vector_count(&self) -> Option<u64> {
+        None
+    }
+
+    fn read_units(&self) -> Option<u64> {
+        None
+    }
+
+    fn total_tokens(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Runs `operation` and reports it to `observer`, if any.
+pub(crate) async fn observe<T, Fut>(
+    observer: Option<&Arc<dyn MetricsObserver>>,
+    operation: &'static str,
+    index_host: Option<&str>,
+    future: Fut,
+) -> Result<T, PineconeError>
+where
+    T: OperationUsage,
+    Fut: Future<Output = Result<T, PineconeError>>,
+{
+    let Some(observer) = observer else {
+        return future.await;
+    };
+
+    let start = Instant::now();
+    let res = future.await;
+
+    let response = res.as_ref().ok();
+    observer.observe(&OperationEvent {
+        operation,
+        index_host,
+        duration: start.elapsed(),
+        outcome: OperationOutcome::of(&res),
+        error: res.as_ref().err(),
+        vector_count: response.and_then(OperationUsage::vector_count),
+        read_units: response.and_then(OperationUsage::read_units),
+        total_tokens: response.and_then(OperationUsage::total_tokens),
+    });
+
+    res
+}
+
+/// Reports `operation`, which failed with `error` before a request was sent, to `observer`, if any.
+#[cfg(feature = "data")]
+pub(crate) fn observe_rejected(
+    observer: Option<&Arc<dyn MetricsObserver>>,
+    operation: &'static str,
+    index_host: Option<&str>,
+    error: &PineconeError,
+) {
+    if let Some(observer) = observer {
+        observer.observe(&OperationEvent {
+            operation,
+            index_host,
+            duration: Duration::ZERO,
+            outcome: OperationOutcome::of_error(error),
+            error: Some(error),
+            vector_count: None,
+            read_units: None,
+            total_tokens: None,
+        });
+    }
+}
+
+mod usage {
+    use super::OperationUsage;
+    use crate::openapi::models as openapi;
+    #[cfg(feature = "data")]
+    use crate::protos;
+
+    impl OperationUsage for () {}
+    impl OperationUsage for openapi::IndexModel {}
+    impl OperationUsage for openapi::IndexList {}
+    impl OperationUsage for openapi::CollectionModel {}
+    impl OperationUsage for openapi::CollectionList {}
+    #[cfg(feature = "data")]
+    impl OperationUsage for protos::UpdateResponse {}
+    #[cfg(feature = "data")]
+    impl OperationUsage for protos::DeleteResponse {}
+    #[cfg(feature = "data")]
+    impl OperationUsage for protos::DescribeIndexStatsResponse {}
+
+    #[cfg(feature = "data")]
+    impl OperationUsage for protos::UpsertResponse {
+        fn vector_count(&self) -> Option<u64> {
+            Some(self.upserted_count.into())
+        }
+    }
+
+    #[cfg(feature = "data")]
+    impl OperationUsage for protos::QueryResponse {
+        fn vector_count(&self) -> Option<u64> {
+            Some(self.matches.len() as u64)
+        }
+
+        fn read_units(&self) -> Option<u64> {
+            self.usage.and_then(|usage| usage.read_units).map(u64::from)
+        }
+    }
+
+    #[cfg(feature = "data")]
+    impl OperationUsage for protos::FetchResponse {
+        fn vector_count(&self) -> Option<u64> {
+            Some(self.vectors.len() as u64)
+        }
+
+        fn read_units(&self) -> Option<u64> {
+            self.usage.and_then(|usage| usage.read_units).map(u64::from)
+        }
+    }
+
+    #[cfg(feature = "data")]
+    impl OperationUsage for protos::ListResponse {
+        fn vector_count(&self) -> Option<u64> {
+            Some(self.vectors.len() as u64)
+        }
+
+        fn read_units(&self) -> Option<u64> {
+            self.usage.and_then(|usage| usage.read_units).map(u64::from)
+        }
+    }
+
+    impl OperationUsage for openapi::EmbeddingsList {
+        fn total_tokens(&self) -> Option<u64> {
+            self.usage
+                .as_ref()
+                .and_then(|usage| usage.total_tokens)
+                .and_then(|tokens| u64::try_from(tokens).ok())
+        }
+    }
+}
+
+/// A `MetricsObserver` that records operations with the `metrics` crate facade, to be exported by the installed
+/// recorder, such as a Prometheus exporter.
+///
+/// The following metrics are labeled with the `operation` and, for the first two, its `outcome`:
+/// * `pinecone_operations_total` - A counter of operations.
+/// * `pinecone_operation_duration_seconds` - A histogram of the duration of operations.
+/// * `pinecone_vectors_total` - A counter of vectors upserted, fetched, listed or matched.
+/// * `pinecone_read_units_total` - A counter of read units consumed.
+/// * `pinecone_embedding_tokens_total` - A counter of tokens embedded.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Default)]
+pub struct MetricsRecorderObserver;
+
+#[cfg(feature = "metrics")]
+impl MetricsObserver for MetricsRecorderObserver {
+    fn observe(&self, event: &OperationEvent<'_>) {
+        let operation = event.operation;
+        let outcome = event.outcome.as_str();
+
+        metrics::counter!("pinecone_operations_total", "operation" => operation, "outcome" => outcome)
+            .increment(1);
+        metrics::histogram!(
+            "pinecone_operation_duration_seconds",
+            "operation" => operation,
+            "outcome" => outcome
+        )
+        .record(event.duration.as_secs_f64());
+
+        if let Some(count) = event.vector_count {
+            metrics::counter!("pinecone_vectors_total", "operation" => operation).increment(count);
+        }
+        if let Some(read_units) = event.read_units {
+            metrics::counter!("pinecone_read_units_total", "operation" => operation)
+                .increment(read_units);
+        }
+        if let Some(tokens) = event.total_tokens {
+            metrics::counter!("pinecone_embedding_tokens_total", "operation" => operation)
+                .increment(tokens);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(not(any(feature = "control", feature = "data")), allow(dead_code))]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // The operation, outcome, vector count and read units of an event
+    type RecordedEvent = (&'static str, OperationOutcome, Option<u64>, Option<u64>);
+
+    /// An observer that keeps the operation, outcome and usage of the events it receives.
+    #[derive(Debug, Default)]
+    pub(crate) struct RecordingObserver {
+        pub(crate) events: Mutex<Vec<RecordedEvent>>,
+    }
+
+    impl MetricsObserver for RecordingObserver {
+        fn observe(&self, event: &OperationEvent<'_>) {
+            self.events.lock().unwrap().push((
+                event.operation,
+                event.outcome,
+                event.vector_count,
+                event.read_units,
+            ));
+        }
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn test_observe() {
+        let recording = Arc::new(RecordingObserver::default());
+        let observer: Arc<dyn MetricsObserver> = recording.clone();
+
+        let response = crate::protos::FetchResponse {
+            usage: Some(crate::protos::Usage {
+                read_units: Some(5),
+            }),
+            ..Default::default()
+        };
+        observe(Some(&observer), "fetch", None, async { Ok(response) })
+            .await
+            .unwrap();
+
+        let _ = observe(Some(&observer), "delete_index", None, async {
+            Err::<(), _>(tonic::Status::unavailable("unavailable").into())
+        })
+        .await;
+
+        let _ = observe(Some(&observer), "describe_index_stats", None, async {
+            Err::<(), _>(tonic::Status::invalid_argument("invalid").into())
+        })
+        .await;
+
+        assert_eq!(
+            *recording.events.lock().unwrap(),
+            vec![
+                ("fetch", OperationOutcome::Success, Some(0), Some(5)),
+                ("delete_index", OperationOutcome::TransientError, None, None),
+                (
+                    "describe_index_stats",
+                    OperationOutcome::ClientError,
+                    None,
+                    None
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn test_metrics_recorder_observer() {
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            MetricsRecorderObserver.observe(&OperationEvent {
+                operation: "query",
+                index_host: None,
+                duration: Duration::from_millis(10),
+                outcome: OperationOutcome::Success,
+                error: None,
+                vector_count: Some(3),
+                read_units: Some(6),
+                total_tokens: None,
+            });
+        });
+
+        let metrics = snapshotter.snapshot().into_vec();
+        let counter = |name: &str| {
+            metrics.iter().find_map(
+                |(key, _, _, value)| match (key.key().name() == name, value) {
+                    (true, DebugValue::Counter(value)) => Some(*value),
+                    _ => None,
+                },
+            )
+        };
+
+        assert_eq!(counter("pinecone_operations_total"), Some(1));
+        assert_eq!(counter("pinecone_vectors_total"), Some(3));
+        assert_eq!(counter("pinecone_read_units_total"), Some(6));
+        assert_eq!(counter("pinecone_embedding_tokens_total"), None);
+    }
+}
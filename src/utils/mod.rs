@@ -3,3 +3,23 @@ pub mod errors;
 
 /// Module for handling user-agent strings.
 pub mod user_agent;
+
+/// Module for retrying requests that fail with transient errors.
+pub(crate) mod retry;
+
+/// Module for caching index hosts by index name.
+#[cfg_attr(not(all(feature = "control", feature = "data")), allow(dead_code))]
+pub(crate) mod host_cache;
+
+/// Module for validating vectors and queries against an index description.
+#[cfg(feature = "data")]
+pub(crate) mod validation;
+
+/// Module for tunneling data plane connections through an HTTP proxy.
+pub(crate) mod proxy;
+
+/// Module for observing the latency, errors and usage of operations.
+pub mod metrics;
+
+/// Module for recording tracing spans and propagating the OpenTelemetry context.
+pub(crate) mod telemetry;
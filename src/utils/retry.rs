@@ -0,0 +1,152 @@
+use crate::models::RetryPolicy;
+use crate::utils::errors::PineconeError;
+use crate::utils::telemetry;
+use std::future::Future;
+
+/// Runs `operation` until it succeeds, fails with an error that is not retryable under `policy`,
+/// or runs out of attempts.
+pub(crate) async fn with_retry<T, F, Fut>(
+    policy: &RetryPolicy,
+    operation: F,
+) -> Result<T, PineconeError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, PineconeError>>,
+{
+    let mut attempt = 1;
+
+    loop {
+        match operation().await {
+            Ok(res) => return Ok(res),
+            Err(e) if policy.should_retry(&e, attempt) => {
+                let delay = policy.delay(attempt);
+                telemetry::record_retry(&e, attempt, delay);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                telemetry::record_error(&e);
+                return Err(e);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "data"))]
+mod tests {
+    use super::*;
+    use crate::utils::errors::TransientErrorKind;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+    use tokio;
+
+    fn test_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            jitter: 0.0,
+            ..Default::default()
+        }
+    }
+
+    fn unavailable() -> PineconeError {
+        tonic::Status::unavailable("unavailable").into()
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_success() {
+        let attempts = AtomicU32::new(0);
+
+        let res = with_retry(&test_policy(3), || async {
+            match attempts.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(unavailable()),
+                _ => Ok("ok"),
+            }
+        })
+        .await
+        .expect("Expected retry to succeed");
+
+        assert_eq!(res, "ok");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_max_attempts() {
+        let attempts = AtomicU32::new(0);
+
+        let res: Result<(), PineconeError> = with_retry(&test_policy(4), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(unavailable())
+        })
+        .await;
+
+        assert!(matches!(
+            res,
+            Err(PineconeError::DataPlaneUnavailableError { .. })
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_retry_not_retryable() {
+        let attempts = AtomicU32::new(0);
+
+        let res: Result<(), PineconeError> = with_retry(&test_policy(4), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(tonic::Status::invalid_argument("invalid").into())
+        })
+        .await;
+
+        assert!(res.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_error_kind_not_enabled() {
+        let attempts = AtomicU32::new(0);
+        let policy = RetryPolicy {
+            retryable_errors: vec![TransientErrorKind::RateLimited],
+            ..test_policy(4)
+        };
+
+        let res: Result<(), PineconeError> = with_retry(&policy, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(unavailable())
+        })
+        .await;
+
+        assert!(res.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_delay() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            jitter: 0.0,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(350));
+        assert_eq!(policy.delay(40), Duration::from_millis(350));
+    }
+
+    #[tokio::test]
+    async fn test_retry_delay_jitter() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            jitter: 0.5,
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let delay = policy.delay(1);
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(100));
+        }
+    }
+}
@@ -0,0 +1,232 @@
+#[cfg(feature = "data")]
+use crate::protos::Usage;
+use crate::utils::errors::PineconeError;
+
+/// Records the kind of `error`, such as `DataPlaneUnavailableError`, in the `error.kind` field of the current span.
+#[cfg(feature = "tracing")]
+pub(crate) fn record_error(error: &PineconeError) {
+    tracing::Span::current().record("error.kind", error_kind(error));
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record_error(_error: &PineconeError) {}
+
+/// Records the read units consumed by an operation in the `read_units` field of the current span.
+#[cfg(all(feature = "data", feature = "tracing"))]
+pub(crate) fn record_usage(usage: Option<&Usage>) {
+    if let Some(read_units) = usage.and_then(|usage| usage.read_units) {
+        tracing::Span::current().record("read_units", read_units);
+    }
+}
+
+#[cfg(all(feature = "data", not(feature = "tracing")))]
+pub(crate) fn record_usage(_usage: Option<&Usage>) {}
+
+/// Records an attempt that failed with a retryable error as an event of the current span.
+#[cfg(feature = "tracing")]
+pub(crate) fn record_retry(error: &PineconeError, attempt: u32, delay: std::time::Duration) {
+    tracing::debug!(
+        error.kind = error_kind(error),
+        attempt,
+        ?delay,
+        "retrying after transient error"
+    );
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record_retry(_error: &PineconeError, _attempt: u32, _delay: std::time::Duration) {}
+
+// The name of the variant of the error
+#[cfg(feature = "tracing")]
+fn error_kind(error: &PineconeError) -> &'static str {
+    match error {
+        PineconeError::UnknownResponseError { .. } => "UnknownResponseError",
+        PineconeError::ActionForbiddenError { .. } => "ActionForbiddenError",
+        PineconeError::APIKeyMissingError { .. } => "APIKeyMissingError",
+        PineconeError::InvalidHeadersError { .. } => "InvalidHeadersError",
+        PineconeError::TimeoutError { .. } => "TimeoutError",
+        PineconeError::ConnectionError { .. } => "ConnectionError",
+        PineconeError::ReqwestError { .. } => "ReqwestError",
+        PineconeError::SerdeError { .. } => "SerdeError",
+        PineconeError::IoError { .. } => "IoError",
+        PineconeError::BadRequestError { .. } => "BadRequestError",
+        PineconeError::UnauthorizedError { .. } => "UnauthorizedError",
+        PineconeError::PodQuotaExceededError { .. } => "PodQuotaExceededError",
+        PineconeError::CollectionsQuotaExceededError { .. } => "CollectionsQuotaExceededError",
+        PineconeError::InvalidCloudError { .. } => "InvalidCloudError",
+        PineconeError::InvalidRegionError { .. } => "InvalidRegionError",
+        PineconeError::InvalidConfigurationError { .. } => "InvalidConfigurationError",
+        PineconeError::InvalidFilterError { .. } => "InvalidFilterError",
+        PineconeError::InvalidMetadataError { .. } => "InvalidMetadataError",
+        PineconeError::ValidationError { .. } => "ValidationError",
+        PineconeError::CollectionNotFoundError { .. } => "CollectionNotFoundError",
+        PineconeError::IndexNotFoundError { .. } => "IndexNotFoundError",
+        PineconeError::ResourceAlreadyExistsError { .. } => "ResourceAlreadyExistsError",
+        PineconeError::UnprocessableEntityError { .. } => "UnprocessableEntityError",
+        PineconeError::PendingCollectionError { .. } => "PendingCollectionError",
+        PineconeError::RateLimitedError { .. } => "RateLimitedError",
+        PineconeError::ServiceUnavailableError { .. } => "ServiceUnavailableError",
+        PineconeError::InternalServerError { .. } => "InternalServerError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlaneError { .. } => "DataPlaneError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlaneNotFoundError { .. } => "DataPlaneNotFoundError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlaneInvalidArgumentError { .. } => "DataPlaneInvalidArgumentError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlaneUnauthenticatedError { .. } => "DataPlaneUnauthenticatedError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlanePermissionDeniedError { .. } => "DataPlanePermissionDeniedError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlaneResourceExhaustedError { .. } => "DataPlaneResourceExhaustedError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlaneUnavailableError { .. } => "DataPlaneUnavailableError",
+        #[cfg(feature = "data")]
+        PineconeError::DataPlaneDeadlineExceededError { .. } => "DataPlaneDeadlineExceededError",
+        #[cfg(feature = "data")]
+        PineconeError::InferenceError { .. } => "InferenceError",
+        // variants added to the non-exhaustive error are recorded under its type until they are listed here
+        #[allow(unreachable_patterns)]
+        _ => "PineconeError",
+    }
+}
+
+/// Injects the OpenTelemetry context of the current span into the metadata of a gRPC request.
+#[cfg(all(feature = "data", feature = "opentelemetry"))]
+pub(crate) fn inject_grpc_context(metadata: &mut tonic::metadata::MetadataMap) {
+    use tonic::metadata::{MetadataKey, MetadataValue};
+
+    struct MetadataInjector<'a>(&'a mut tonic::metadata::MetadataMap);
+
+    impl opentelemetry::propagation::Injector for MetadataInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            if let (Ok(key), Ok(value)) = (
+                MetadataKey::from_bytes(key.as_bytes()),
+                MetadataValue::try_from(value),
+            ) {
+                self.0.insert(key, value);
+            }
+        }
+    }
+
+    inject_context(&mut MetadataInjector(metadata));
+}
+
+#[cfg(all(feature = "data", not(feature = "opentelemetry")))]
+pub(crate) fn inject_grpc_context(_metadata: &mut tonic::metadata::MetadataMap) {}
+
+/// Injects the OpenTelemetry context of the current span into the headers of an HTTP request.
+#[cfg(feature = "opentelemetry")]
+fn inject_http_context(headers: &mut reqwest::header::HeaderMap) {
+    use reqwest::header::{HeaderName, HeaderValue};
+
+    struct HeaderInjector<'a>(&'a mut reqwest::header::HeaderMap);
+
+    impl opentelemetry::propagation::Injector for HeaderInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            if let (Ok(key), Ok(value)) = (
+                HeaderName::from_bytes(key.as_bytes()),
+                HeaderValue::from_str(&value),
+            ) {
+                self.0.insert(key, value);
+            }
+        }
+    }
+
+    inject_context(&mut HeaderInjector(headers));
+}
+
+#[cfg(feature = "opentelemetry")]
+fn inject_context(injector: &mut dyn opentelemetry::propagation::Injector) {
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let context = tracing::Span::current().context();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, injector)
+    });
+}
+
+/// A middleware that propagates the OpenTelemetry context of the current span in the headers of control plane
+/// and inference requests.
+#[cfg(feature = "opentelemetry")]
+struct TraceContextMiddleware;
+
+#[cfg(feature = "opentelemetry")]
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for TraceContextMiddleware {
+    async fn handle(
+        &self,
+        mut request: reqwest::Request,
+        extensions: &mut http::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        inject_http_context(request.headers_mut());
+        next.run(request, extensions).await
+    }
+}
+
+/// Wraps the HTTP client of the control plane with the telemetry middleware, if enabled.
+pub(crate) fn with_middleware(client: reqwest::Client) -> reqwest_middleware::ClientWithMiddleware {
+    let builder = reqwest_middleware::ClientBuilder::new(client);
+    #[cfg(feature = "opentelemetry")]
+    let builder = builder.with(TraceContextMiddleware);
+
+    builder.build()
+}
+
+#[cfg(all(test, feature = "opentelemetry"))]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::TracerProvider;
+    use tokio;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    // Runs `f` in a span exported to OpenTelemetry, with the W3C trace context propagator
+    fn in_span<T>(f: impl FnOnce() -> T) -> T {
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+        let tracer = TracerProvider::builder().build().tracer("test");
+        let subscriber =
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("test");
+            let _guard = span.enter();
+            f()
+        })
+    }
+
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn test_inject_grpc_context() {
+        let mut metadata = tonic::metadata::MetadataMap::new();
+        in_span(|| inject_grpc_context(&mut metadata));
+
+        let traceparent = metadata.get("traceparent").expect("Expected traceparent");
+        assert!(traceparent.to_str().unwrap().starts_with("00-"));
+    }
+
+    #[tokio::test]
+    async fn test_inject_http_context() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        in_span(|| inject_http_context(&mut headers));
+
+        let traceparent = headers.get("traceparent").expect("Expected traceparent");
+        assert!(traceparent.to_str().unwrap().starts_with("00-"));
+    }
+
+    #[tokio::test]
+    async fn test_error_kind() {
+        #[cfg(feature = "data")]
+        {
+            let error = PineconeError::from(tonic::Status::unavailable("unavailable"));
+            assert_eq!(error_kind(&error), "DataPlaneUnavailableError");
+        }
+
+        let error = PineconeError::InvalidFilterError {
+            message: "message".to_string(),
+        };
+        assert_eq!(error_kind(&error), "InvalidFilterError");
+    }
+}
@@ -0,0 +1,369 @@
+use crate::models::{metadata_to_json, IndexModel, Metadata, Metric, SparseValues, Vector};
+use crate::protos::{QueryRequest, UpdateRequest};
+use crate::utils::errors::PineconeError;
+
+/// The maximum size of the metadata of a vector accepted by Pinecone, in bytes of JSON.
+pub(crate) const MAX_METADATA_BYTES: usize = 40 * 1024;
+
+/// Validates vectors and queries against the dimension and metric of an index before they are sent.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VectorValidator {
+    dimension: usize,
+    metric: Metric,
+}
+
+impl VectorValidator {
+    /// Creates a validator for the index described by `index`.
+    pub(crate) fn new(index: &IndexModel) -> Self {
+        VectorValidator {
+            dimension: index.dimension.max(0) as usize,
+            metric: index.metric.clone(),
+        }
+    }
+
+    /// Validates a vector to upsert.
+    pub(crate) fn validate_vector(&self, vector: &Vector) -> Result<(), PineconeError> {
+        let id = Some(vector.id.as_str());
+        validate_id(&vector.id)?;
+        self.validate_values(id, "values", &vector.values)?;
+        if let Some(sparse_values) = &vector.sparse_values {
+            self.validate_sparse_values(id, "sparse_values", sparse_values)?;
+        }
+        if let Some(metadata) = &vector.metadata {
+            validate_metadata(id, "metadata", metadata)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates an update request. Empty values are not updated, so they are not checked against the dimension.
+    pub(crate) fn validate_update(&self, request: &UpdateRequest) -> Result<(), PineconeError> {
+        let id = Some(request.id.as_str());
+        validate_id(&request.id)?;
+        if !request.values.is_empty() {
+            self.validate_values(id, "values", &request.values)?;
+        }
+        if let Some(sparse_values) = &request.sparse_values {
+            self.validate_sparse_values(id, "sparse_values", sparse_values)?;
+        }
+        if let Some(metadata) = &request.set_metadata {
+            validate_metadata(id, "set_metadata", metadata)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates a query request, either by ID or by dense and/or sparse vector.
+    pub(crate) fn validate_query(&self, request: &QueryRequest) -> Result<(), PineconeError> {
+        if !request.id.is_empty() {
+            return Ok(());
+        }
+
+        if !request.vector.is_empty() || request.sparse_vector.is_none() {
+            self.validate_values(None, "vector", &request.vector)?;
+        }
+        if let Some(sparse_vector) = &request.sparse_vector {
+            self.validate_sparse_values(None, "sparse_vector", sparse_vector)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_values(
+        &self,
+        id: Option<&str>,
+        field: &str,
+        values: &[f32],
+    ) -> Result<(), PineconeError> {
+        if values.len() != self.dimension {
+            return Err(validation_error(
+                id,
+                field,
+                format!(
+                    "Dimension {} does not match the dimension of the index {}",
+                    values.len(),
+                    self.dimension
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn validate_sparse_values(
+        &self,
+        id: Option<&str>,
+        field: &str,
+        sparse_values: &SparseValues,
+    ) -> Result<(), PineconeError> {
+        if self.metric != Metric::Dotproduct {
+            return Err(validation_error(
+                id,
+                field,
+                format!(
+                    "Sparse values require the dotproduct metric, but the index uses {:?}",
+                    self.metric
+                ),
+            ));
+        }
+
+        if sparse_values.indices.len() != sparse_values.values.len() {
+            return Err(validation_error(
+                id,
+                field,
+                format!(
+                    "Number of indices {} does not match number of values {}",
+                    sparse_values.indices.len(),
+                    sparse_values.values.len()
+                ),
+            ));
+        }
+
+        if sparse_values.indices.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(validation_error(
+                id,
+                field,
+                "Indices must be sorted in increasing order and unique".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// Helper function to create a validation error
+fn validation_error(id: Option<&str>, field: &str, message: String) -> PineconeError {
+    PineconeError::ValidationError {
+        id: id.map(str::to_string),
+        field: field.to_string(),
+        message,
+    }
+}
+
+fn validate_id(id: &str) -> Result<(), PineconeError> {
+    if id.is_empty() {
+        return Err(validation_error(
+            Some(id),
+            "id",
+            "ID must not be empty".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+// Pinecone measures the size of metadata as the length of its JSON serialization, whatever the transport
+fn validate_metadata(
+    id: Option<&str>,
+    field: &str,
+    metadata: &Metadata,
+) -> Result<(), PineconeError> {
+    let size = metadata_to_json(metadata)
+        .map(|json| json.to_string().len())
+        .map_err(|e| validation_error(id, field, e.to_string()))?;
+    if size > MAX_METADATA_BYTES {
+        return Err(validation_error(
+            id,
+            field,
+            format!("Metadata size {size} bytes exceeds the limit of {MAX_METADATA_BYTES} bytes"),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Kind, Value};
+    use std::collections::BTreeMap;
+    use tokio;
+
+    fn validator(metric: Metric) -> VectorValidator {
+        VectorValidator::new(&IndexModel {
+            dimension: 3,
+            metric,
+            ..Default::default()
+        })
+    }
+
+    fn vector(id: &str, values: Vec<f32>) -> Vector {
+        Vector {
+            id: id.to_string(),
+            values,
+            sparse_values: None,
+            metadata: None,
+        }
+    }
+
+    fn assert_validation_error(
+        result: Result<(), PineconeError>,
+        expected_id: Option<&str>,
+        expected_field: &str,
+    ) {
+        match result {
+            Err(PineconeError::ValidationError { id, field, .. }) => {
+                assert_eq!(id.as_deref(), expected_id);
+                assert_eq!(field, expected_field);
+            }
+            res => panic!("Expected validation error, got {:?}", res),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_vector() {
+        let validator = validator(Metric::Dotproduct);
+
+        let mut valid = vector("id1", vec![1.0, 2.0, 3.0]);
+        valid.sparse_values = Some(SparseValues {
+            indices: vec![1, 5],
+            values: vec![0.5, 0.5],
+        });
+        assert!(validator.validate_vector(&valid).is_ok());
+
+        assert_validation_error(
+            validator.validate_vector(&vector("id1", vec![1.0, 2.0])),
+            Some("id1"),
+            "values",
+        );
+        assert_validation_error(
+            validator.validate_vector(&vector("", vec![1.0, 2.0, 3.0])),
+            Some(""),
+            "id",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_sparse_values() {
+        let validator = validator(Metric::Dotproduct);
+
+        for (indices, values) in [
+            (vec![1, 5], vec![0.5]),
+            (vec![5, 1], vec![0.5, 0.5]),
+            (vec![1, 1], vec![0.5, 0.5]),
+        ] {
+            let mut invalid = vector("id1", vec![1.0, 2.0, 3.0]);
+            invalid.sparse_values = Some(SparseValues { indices, values });
+
+            assert_validation_error(
+                validator.validate_vector(&invalid),
+                Some("id1"),
+                "sparse_values",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_sparse_values_metric() {
+        let mut invalid = vector("id1", vec![1.0, 2.0, 3.0]);
+        invalid.sparse_values = Some(SparseValues {
+            indices: vec![1],
+            values: vec![0.5],
+        });
+
+        assert_validation_error(
+            validator(Metric::Cosine).validate_vector(&invalid),
+            Some("id1"),
+            "sparse_values",
+        );
+    }
+
+    // Metadata whose JSON serialization, `{"text":"aaa..."}`, is `size` bytes
+    fn metadata_of_size(size: usize) -> Metadata {
+        Metadata {
+            fields: BTreeMap::from([(
+                "text".to_string(),
+                Value {
+                    kind: Some(Kind::StringValue("a".repeat(size - r#"{"text":""}"#.len()))),
+                },
+            )]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validate_metadata_size() {
+        let mut valid = vector("id1", vec![1.0, 2.0, 3.0]);
+        valid.metadata = Some(metadata_of_size(MAX_METADATA_BYTES));
+        assert!(validator(Metric::Cosine).validate_vector(&valid).is_ok());
+
+        let mut invalid = vector("id1", vec![1.0, 2.0, 3.0]);
+        invalid.metadata = Some(metadata_of_size(MAX_METADATA_BYTES + 1));
+        assert_validation_error(
+            validator(Metric::Cosine).validate_vector(&invalid),
+            Some("id1"),
+            "metadata",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_metadata_size_json() {
+        // small integers take 9 bytes in protobuf, but 1 byte in JSON
+        let metadata = Metadata {
+            fields: (0..4000)
+                .map(|i| {
+                    let value = Value {
+                        kind: Some(Kind::NumberValue(1.0)),
+                    };
+                    (format!("{i:04}"), value)
+                })
+                .collect(),
+        };
+        let mut valid = vector("id1", vec![1.0, 2.0, 3.0]);
+        valid.metadata = Some(metadata);
+        assert!(validator(Metric::Cosine).validate_vector(&valid).is_ok());
+
+        // escaped characters are longer in JSON than in protobuf
+        let metadata = Metadata {
+            fields: BTreeMap::from([(
+                "text".to_string(),
+                Value {
+                    kind: Some(Kind::StringValue("\n".repeat(MAX_METADATA_BYTES / 2))),
+                },
+            )]),
+        };
+        let mut invalid = vector("id1", vec![1.0, 2.0, 3.0]);
+        invalid.metadata = Some(metadata);
+        assert_validation_error(
+            validator(Metric::Cosine).validate_vector(&invalid),
+            Some("id1"),
+            "metadata",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_update() {
+        let validator = validator(Metric::Cosine);
+
+        let request = UpdateRequest {
+            id: "id1".to_string(),
+            ..Default::default()
+        };
+        assert!(validator.validate_update(&request).is_ok());
+
+        let request = UpdateRequest {
+            id: "id1".to_string(),
+            values: vec![1.0],
+            ..Default::default()
+        };
+        assert_validation_error(validator.validate_update(&request), Some("id1"), "values");
+    }
+
+    #[tokio::test]
+    async fn test_validate_query() {
+        let validator = validator(Metric::Cosine);
+
+        #[allow(deprecated)]
+        let by_id = QueryRequest {
+            id: "id1".to_string(),
+            ..Default::default()
+        };
+        assert!(validator.validate_query(&by_id).is_ok());
+
+        #[allow(deprecated)]
+        let by_value = QueryRequest {
+            vector: vec![1.0, 2.0],
+            ..Default::default()
+        };
+        assert_validation_error(validator.validate_query(&by_value), None, "vector");
+    }
+}
@@ -1,3 +1,12 @@
+// These tests predate the newer clippy lints that CI runs with `-D warnings`. The lints are allowed once for the
+// whole test crate rather than at each test, so that new tests still get the warnings that are not listed here.
+#![allow(
+    clippy::result_large_err,
+    clippy::let_unit_value,
+    clippy::cmp_owned,
+    clippy::needless_borrow
+)]
+
 use common::{
     generate_collection_name, generate_index_name, get_collection, get_pod_index,
     get_serverless_index,
@@ -1,4 +1,9 @@
+// These tests predate the newer clippy lints that CI runs with `-D warnings`. The lints are allowed once for the
+// whole test crate rather than at each test, so that new tests still get the warnings that are not listed here.
+#![allow(clippy::result_large_err, clippy::let_unit_value)]
+
 use common::{generate_namespace_name, generate_vector, get_pod_index, get_serverless_index};
+use futures::TryStreamExt;
 use pinecone_sdk::models::{Kind, Metadata, Namespace, SparseValues, Value, Vector};
 use pinecone_sdk::pinecone::default_client;
 use pinecone_sdk::utils::errors::PineconeError;
@@ -47,7 +52,7 @@ async fn test_upsert() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -79,7 +84,7 @@ async fn test_upsert_sliced_vectors() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -112,6 +117,41 @@ async fn test_upsert_sliced_vectors() -> Result<(), PineconeError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_upsert_batched() -> Result<(), PineconeError> {
+    let pinecone = default_client().expect("Failed to create Pinecone instance");
+
+    let host = pinecone
+        .describe_index(&get_serverless_index())
+        .await
+        .unwrap()
+        .host;
+
+    let index = pinecone
+        .index(host.as_str())
+        .await
+        .expect("Failed to target index");
+
+    let vectors: Vec<Vector> = (0..250)
+        .map(|i| Vector {
+            id: i.to_string(),
+            values: generate_vector(4),
+            sparse_values: None,
+            metadata: None,
+        })
+        .collect();
+
+    let upsert_response = index
+        .upsert_batched(&vectors, &generate_namespace_name(), Some(50), Some(2))
+        .await
+        .expect("Failed to upsert");
+
+    assert!(upsert_response.is_success());
+    assert_eq!(upsert_response.upserted_count, 250);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_describe_index_stats_with_filter() -> Result<(), PineconeError> {
     let pinecone = default_client().expect("Failed to create Pinecone instance");
@@ -122,7 +162,7 @@ async fn test_describe_index_stats_with_filter() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -155,7 +195,7 @@ async fn test_describe_index_stats_no_filter() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -180,7 +220,7 @@ async fn test_list_vectors() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -193,6 +233,32 @@ async fn test_list_vectors() -> Result<(), PineconeError> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_list_all_vectors() -> Result<(), PineconeError> {
+    let pinecone = default_client().expect("Failed to create Pinecone instance");
+
+    let host = pinecone
+        .describe_index(&get_serverless_index())
+        .await
+        .unwrap()
+        .host;
+
+    let index = pinecone
+        .index(host.as_str())
+        .await
+        .expect("Failed to target index");
+
+    let ids: Vec<String> = index
+        .list_all(&Default::default(), None, Some(5))
+        .try_collect()
+        .await
+        .expect("Failed to list vectors");
+
+    assert!(ids.len() <= 5);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_query_by_id() -> Result<(), PineconeError> {
     let pinecone = default_client().expect("Failed to create Pinecone instance");
@@ -203,7 +269,7 @@ async fn test_query_by_id() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -226,7 +292,7 @@ async fn test_update_vector() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -266,7 +332,7 @@ async fn test_query_by_value() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -291,7 +357,7 @@ async fn test_update_vector_fail_id() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -320,7 +386,7 @@ async fn test_update_vector_fail_namespace() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -349,7 +415,7 @@ async fn test_delete_vectors_by_ids() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -395,7 +461,7 @@ async fn test_delete_all_vectors() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -439,7 +505,7 @@ async fn test_delete_by_filter() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -512,7 +578,7 @@ async fn test_fetch_vectors() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -586,7 +652,7 @@ async fn test_fetch_no_match() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -613,7 +679,7 @@ async fn test_fetch_empty_id_list() -> Result<(), PineconeError> {
         .unwrap()
         .host;
 
-    let mut index = pinecone
+    let index = pinecone
         .index(host.as_str())
         .await
         .expect("Failed to target index");
@@ -1,3 +1,7 @@
+// These tests predate the newer clippy lints that CI runs with `-D warnings`. The lints are allowed once for the
+// whole test crate rather than at each test, so that new tests still get the warnings that are not listed here.
+#![allow(clippy::result_large_err, clippy::useless_vec)]
+
 use pinecone_sdk::models::EmbedRequestParameters;
 use pinecone_sdk::pinecone::default_client;
 use pinecone_sdk::utils::errors::PineconeError;